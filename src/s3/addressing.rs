@@ -0,0 +1,178 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::{BucketName, Region};
+use surf::{http::url, Url};
+
+/// The way buckets are addressed in request URLs.
+///
+/// With path style, the bucket is the first segment of the
+/// URL path, e.g. `https://s3.amazonaws.com/my-bucket/key`.
+/// With virtual-hosted style, the bucket is a subdomain of the
+/// endpoint, e.g. `https://my-bucket.s3.amazonaws.com/key`.
+///
+/// AWS recommends virtual-hosted style while MinIO deployments
+/// are usually reached via IP addresses or host names without
+/// wildcard DNS records, and hence require path style.
+///
+/// # Example
+/// ```
+/// use minio::s3::{AddressingStyle, Region};
+///
+/// let style = AddressingStyle::Auto;
+/// assert!(style.is_virtual_hosted(&Region::UsWest2, "my-bucket"));
+/// assert!(!style.is_virtual_hosted(&Region::UsWest2, "www.example.com"));
+///
+/// let region = Region::custom("https://minio.example.com:9000").unwrap();
+/// assert!(!style.is_virtual_hosted(&region, "my-bucket"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AddressingStyle {
+    /// Buckets are addressed as first segment of the URL path.
+    #[default]
+    Path,
+
+    /// Buckets are addressed as subdomain of the endpoint.
+    VirtualHosted,
+
+    /// Buckets are addressed as subdomain of AWS endpoints, if
+    /// the bucket name is a valid DNS label.
+    ///
+    /// Path style is used for bucket names that are no valid
+    /// DNS names, or that contain periods and would not match
+    /// the endpoint's TLS certificate, as well as for IP
+    /// endpoints, endpoints with explicit ports and custom
+    /// regions that are not hosted by AWS.
+    Auto,
+}
+
+impl AddressingStyle {
+    /// Reports whether requests for the given bucket in the
+    /// given region use virtual-hosted style.
+    pub fn is_virtual_hosted(self, region: &Region, bucket: &str) -> bool {
+        match self {
+            Self::Path => false,
+            Self::VirtualHosted => true,
+            Self::Auto => {
+                let endpoint = match Url::parse(region.endpoint()) {
+                    Ok(url) => url,
+                    Err(_) => return false,
+                };
+                let host = match endpoint.host() {
+                    Some(url::Host::Domain(host)) => host,
+                    _ => return false,
+                };
+                let name = match BucketName::new(bucket) {
+                    Ok(name) => name,
+                    Err(_) => return false,
+                };
+                let is_aws = region.partition().is_some()
+                    || host.ends_with(".amazonaws.com")
+                    || host.ends_with(".amazonaws.com.cn");
+                is_aws
+                    && endpoint.port().is_none()
+                    && (endpoint.scheme() != "https" || name.is_virtual_host_compatible())
+            }
+        }
+    }
+}
+
+/// Returns the URL of the bucket at the region's endpoint, or
+/// of an object within the bucket if `key` is present. The key
+/// must be URI-encoded.
+pub(crate) fn bucket_url(
+    style: AddressingStyle,
+    region: &Region,
+    endpoint: &str,
+    bucket: &str,
+    key: Option<&str>,
+) -> Result<Url, url::ParseError> {
+    let mut url = match key {
+        Some(key) => Url::parse(&format!("{endpoint}/{bucket}/{key}"))?,
+        None => Url::parse(&format!("{endpoint}/{bucket}"))?,
+    };
+    if !style.is_virtual_hosted(region, bucket) {
+        return Ok(url);
+    }
+
+    let host = match url.host_str() {
+        Some(host) => format!("{}.{}", bucket, host),
+        None => return Ok(url),
+    };
+    let path = match url
+        .path()
+        .strip_prefix('/')
+        .and_then(|path| path.strip_prefix(bucket))
+    {
+        Some("") => String::from("/"),
+        Some(path) => String::from(path),
+        None => return Ok(url),
+    };
+    url.set_host(Some(&host))?;
+    url.set_path(&path);
+    Ok(url)
+}
+
+/// Returns the bucket subdomain, including the trailing period,
+/// of a virtual-hosted URL for the given endpoint, if any.
+pub(crate) fn subdomain(url: &Url, endpoint: &str) -> Option<String> {
+    let endpoint = Url::parse(endpoint).ok()?;
+    let (host, endpoint) = (url.host_str()?, endpoint.host_str()?);
+    host.strip_suffix(endpoint)
+        .filter(|subdomain| subdomain.ends_with('.'))
+        .map(String::from)
+}
+
+/// Reports whether the URL refers to the given endpoint, either
+/// in path or virtual-hosted style.
+pub(crate) fn targets(url: &Url, endpoint: &str) -> bool {
+    match Url::parse(endpoint) {
+        Ok(parsed) if parsed.host_str().is_some() => {
+            parsed.host_str() == url.host_str() || subdomain(url, endpoint).is_some()
+        }
+        // Endpoints without scheme have no host when parsed.
+        _ => true,
+    }
+}
+
+/// Returns the URL of a request rewritten for another endpoint.
+///
+/// The bucket subdomain of a virtual-hosted URL, as returned
+/// by [`subdomain`], is kept.
+pub(crate) fn rebase(
+    url: &Url,
+    endpoint: &str,
+    subdomain: Option<&str>,
+) -> Result<Url, url::ParseError> {
+    let mut rebased = Url::parse(&format!(
+        "{endpoint}{path}",
+        endpoint = endpoint,
+        path = &url[url::Position::BeforePath..]
+    ))?;
+    if let (Some(subdomain), Some(host)) = (subdomain, rebased.host_str()) {
+        let host = format!("{}{}", subdomain, host);
+        rebased.set_host(Some(&host))?;
+    }
+    Ok(rebased)
+}
+
+/// Returns the set of regions a request to the URL is signed
+/// for with SigV4A, if the URL refers to a Multi-Region Access
+/// Point. All other requests are signed with SigV4.
+pub(crate) fn region_set(url: &Url) -> Option<&'static str> {
+    let host = url.host_str()?;
+    let alias = host.strip_suffix(".accesspoint.s3-global.amazonaws.com")?;
+    alias.ends_with(".mrap").then_some("*")
+}
@@ -0,0 +1,138 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+/// The strategy for distributing requests across the
+/// endpoints of a region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Strategy {
+    /// Sends all requests to the primary endpoint and only
+    /// uses the other endpoints, in order, as backups.
+    #[default]
+    Failover,
+
+    /// Distributes requests evenly across all endpoints.
+    RoundRobin,
+}
+
+/// A load balancer distributing requests across the
+/// endpoints of a multi-endpoint [`Region`](crate::s3::Region).
+///
+/// An endpoint becomes unhealthy when a request to it fails
+/// with a transport error. Unhealthy endpoints are skipped
+/// until their cooldown has passed or no healthy endpoint
+/// is left. Requests without a body fail over to the next
+/// endpoint immediately.
+///
+/// Cloning a `Balancer` is cheap and the clones share their
+/// state.
+///
+/// # Example
+/// ```
+/// use minio::s3::{balancer::Strategy, Balancer};
+/// use std::time::Duration;
+///
+/// let balancer = Balancer::new(Strategy::RoundRobin).with_cooldown(Duration::from_secs(10));
+/// assert_eq!(Strategy::RoundRobin, balancer.strategy());
+/// assert_eq!(Duration::from_secs(10), balancer.cooldown());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Balancer {
+    strategy: Strategy,
+    cooldown: Duration,
+    next: Arc<AtomicUsize>,
+    unhealthy: Arc<Mutex<HashMap<String, Instant>>>,
+}
+
+impl Balancer {
+    /// Returns a new load balancer using the given strategy.
+    ///
+    /// Unhealthy endpoints are skipped for 30 seconds by default.
+    pub fn new(strategy: Strategy) -> Self {
+        Self {
+            strategy,
+            cooldown: Duration::from_secs(30),
+            next: Arc::new(AtomicUsize::new(0)),
+            unhealthy: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Sets the time an unhealthy endpoint is skipped.
+    pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    #[inline]
+    pub fn strategy(&self) -> Strategy {
+        self.strategy
+    }
+
+    #[inline]
+    pub fn cooldown(&self) -> Duration {
+        self.cooldown
+    }
+
+    /// Returns the endpoints in the order they should be tried.
+    ///
+    /// Healthy endpoints come first, ordered by the balancing
+    /// strategy, followed by the unhealthy ones.
+    pub(crate) fn candidates<'a>(&self, endpoints: &[&'a str]) -> Vec<&'a str> {
+        let start = match self.strategy {
+            Strategy::Failover => 0,
+            Strategy::RoundRobin if endpoints.len() > 1 => {
+                self.next.fetch_add(1, Ordering::Relaxed) % endpoints.len()
+            }
+            Strategy::RoundRobin => 0,
+        };
+        let ordered = endpoints[start..]
+            .iter()
+            .chain(&endpoints[..start])
+            .copied();
+
+        let now = Instant::now();
+        let mut unhealthy = self.unhealthy.lock().expect("balancer lock");
+        unhealthy.retain(|_, until| now < *until);
+
+        let (mut healthy, down): (Vec<&str>, Vec<&str>) =
+            ordered.partition(|endpoint| !unhealthy.contains_key(*endpoint));
+        healthy.extend(down);
+        healthy
+    }
+
+    pub(crate) fn mark_unhealthy(&self, endpoint: &str) {
+        let mut unhealthy = self.unhealthy.lock().expect("balancer lock");
+        unhealthy.insert(String::from(endpoint), Instant::now() + self.cooldown);
+    }
+
+    pub(crate) fn mark_healthy(&self, endpoint: &str) {
+        let mut unhealthy = self.unhealthy.lock().expect("balancer lock");
+        unhealthy.remove(endpoint);
+    }
+}
+
+impl Default for Balancer {
+    fn default() -> Self {
+        Self::new(Strategy::default())
+    }
+}
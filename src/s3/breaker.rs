@@ -0,0 +1,275 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use surf::Url;
+
+/// A circuit breaker tracking the health of S3 endpoints.
+///
+/// The breaker counts consecutive failures - transport errors
+/// and `5xx` responses - per endpoint. Once the failure threshold
+/// is reached the circuit opens and requests to that endpoint fail
+/// immediately with a [`CircuitOpen`] error until the cooldown
+/// period has passed. Afterwards, a single probe request is let
+/// through. The circuit closes again if the probe succeeds and
+/// re-opens if it fails, or if the probe is dropped before it
+/// completes, e.g. since it timed out or has been cancelled.
+///
+/// Cloning a `CircuitBreaker` is cheap and the clones share their
+/// state. Hence, the same breaker can be used by multiple buckets.
+///
+/// # Example
+/// ```
+/// use minio::s3::CircuitBreaker;
+/// use std::time::Duration;
+///
+/// let breaker = CircuitBreaker::new(5, Duration::from_secs(30));
+/// assert_eq!(5, breaker.threshold());
+/// assert_eq!(Duration::from_secs(30), breaker.cooldown());
+/// ```
+#[derive(Debug, Clone)]
+pub struct CircuitBreaker {
+    threshold: u32,
+    cooldown: Duration,
+    circuits: Arc<Mutex<HashMap<String, Circuit>>>,
+}
+
+/// The state of the circuit of an endpoint.
+///
+/// See: [`CircuitBreaker::state`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests are sent.
+    Closed,
+    /// Requests are rejected until the cooldown has passed.
+    Open,
+    /// The cooldown has passed and a single probe request
+    /// may be sent, or is in flight.
+    HalfOpen,
+}
+
+/// The permission to send a request to an endpoint, as
+/// granted by [`CircuitBreaker::acquire`].
+///
+/// A probe request that is dropped before its outcome has
+/// been recorded re-opens the circuit. Otherwise, the circuit
+/// would stay half-open and reject all requests.
+#[derive(Debug)]
+pub(crate) struct Permit {
+    breaker: CircuitBreaker,
+    endpoint: String,
+    probe: bool,
+}
+
+/// The error returned when a request is rejected because
+/// the circuit of its endpoint is open.
+#[derive(Clone, PartialEq, Eq)]
+pub struct CircuitOpen {
+    endpoint: String,
+}
+
+#[derive(Debug, Default)]
+struct Circuit {
+    failures: u32,
+    state: State,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum State {
+    #[default]
+    Closed,
+    Open {
+        until: Instant,
+    },
+    HalfOpen,
+}
+
+impl CircuitBreaker {
+    /// Returns a new circuit breaker that opens the circuit of an
+    /// endpoint after `threshold` consecutive failures and keeps it
+    /// open for the `cooldown` period.
+    ///
+    /// A `threshold` of `0` is treated as `1`.
+    pub fn new(threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            threshold: threshold.max(1),
+            cooldown,
+            circuits: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Returns the number of consecutive failures after
+    /// which the circuit of an endpoint opens.
+    #[inline]
+    pub fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    /// Returns the time an open circuit rejects requests
+    /// before a probe request is let through.
+    #[inline]
+    pub fn cooldown(&self) -> Duration {
+        self.cooldown
+    }
+
+    /// Returns the state of the circuit of the endpoint of `url`.
+    ///
+    /// An open circuit whose cooldown has passed is half-open.
+    ///
+    /// # Example
+    /// ```
+    /// use minio::s3::{CircuitBreaker, CircuitState};
+    /// use std::time::Duration;
+    ///
+    /// let breaker = CircuitBreaker::new(5, Duration::from_secs(30));
+    /// let url = "https://s3.amazonaws.com".parse().unwrap();
+    /// assert_eq!(CircuitState::Closed, breaker.state(&url));
+    /// ```
+    pub fn state(&self, url: &Url) -> CircuitState {
+        let circuits = self.circuits.lock().expect("circuit breaker lock");
+        match circuits.get(&endpoint(url)).map(|circuit| circuit.state) {
+            Some(State::Open { until }) if Instant::now() < until => CircuitState::Open,
+            Some(State::Open { .. } | State::HalfOpen) => CircuitState::HalfOpen,
+            Some(State::Closed) | None => CircuitState::Closed,
+        }
+    }
+
+    /// Reports whether the circuit of the endpoint of `url`
+    /// is open and rejects all requests.
+    ///
+    /// Half-open circuits are not open.
+    ///
+    /// # Example
+    /// ```
+    /// use minio::s3::CircuitBreaker;
+    /// use std::time::Duration;
+    ///
+    /// let breaker = CircuitBreaker::new(5, Duration::from_secs(30));
+    /// assert!(!breaker.is_open(&"https://s3.amazonaws.com".parse().unwrap()));
+    /// ```
+    #[inline]
+    pub fn is_open(&self, url: &Url) -> bool {
+        self.state(url) == CircuitState::Open
+    }
+
+    /// Checks whether a request to the endpoint of `url` may be sent.
+    ///
+    /// An open circuit whose cooldown has passed transitions into
+    /// the half-open state and admits exactly one probe request.
+    /// The outcome of the request is recorded with the permit.
+    pub(crate) fn acquire(&self, url: &Url) -> Result<Permit, CircuitOpen> {
+        let endpoint = endpoint(url);
+        let mut circuits = self.circuits.lock().expect("circuit breaker lock");
+        let probe = match circuits.get_mut(&endpoint) {
+            None => false,
+            Some(circuit) => match circuit.state {
+                State::Closed => false,
+                State::Open { until } if Instant::now() >= until => {
+                    circuit.state = State::HalfOpen;
+                    true
+                }
+                State::Open { .. } | State::HalfOpen => return Err(CircuitOpen { endpoint }),
+            },
+        };
+        Ok(Permit {
+            breaker: self.clone(),
+            endpoint,
+            probe,
+        })
+    }
+
+    /// Records the outcome of a request to the endpoint.
+    fn record(&self, endpoint: &str, success: bool) {
+        let mut circuits = self.circuits.lock().expect("circuit breaker lock");
+        if success {
+            circuits.remove(endpoint);
+            return;
+        }
+
+        let circuit = circuits.entry(String::from(endpoint)).or_default();
+        circuit.failures = circuit.failures.saturating_add(1);
+        if circuit.state == State::HalfOpen || circuit.failures >= self.threshold {
+            circuit.state = State::Open {
+                until: Instant::now() + self.cooldown,
+            };
+        }
+    }
+}
+
+// === Permit ===
+
+impl Permit {
+    /// Records the outcome of the request.
+    pub(crate) fn record(mut self, success: bool) {
+        self.probe = false;
+        self.breaker.record(&self.endpoint, success);
+    }
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        if !self.probe {
+            return;
+        }
+        let mut circuits = self.breaker.circuits.lock().expect("circuit breaker lock");
+        if let Some(circuit) = circuits.get_mut(&self.endpoint) {
+            if circuit.state == State::HalfOpen {
+                circuit.state = State::Open {
+                    until: Instant::now() + self.breaker.cooldown,
+                };
+            }
+        }
+    }
+}
+
+// === CircuitOpen ===
+
+impl CircuitOpen {
+    /// Returns the endpoint whose circuit is open.
+    #[inline]
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+}
+
+impl std::error::Error for CircuitOpen {}
+
+impl fmt::Display for CircuitOpen {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "circuit open for S3 endpoint: {}", self.endpoint)
+    }
+}
+
+impl fmt::Debug for CircuitOpen {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CircuitOpen")
+            .field("endpoint", &self.endpoint)
+            .finish()
+    }
+}
+
+/// Returns the `host:port` pair identifying the endpoint of `url`.
+fn endpoint(url: &Url) -> String {
+    format!(
+        "{host}:{port}",
+        host = url.host_str().unwrap_or_default(),
+        port = url.port_or_known_default().unwrap_or_default()
+    )
+}
@@ -0,0 +1,86 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::{paginate::TokenPage, BucketName, InvalidMetadata};
+use serde_derive::Deserialize;
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+/// Information about a bucket as returned by a bucket listing.
+///
+/// See: [`Client::list_buckets`](crate::s3::Client::list_buckets)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BucketInfo {
+    name: BucketName,
+    creation_date: OffsetDateTime,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListAllMyBucketsResult {
+    #[serde(rename(deserialize = "Buckets"), default)]
+    buckets: BucketsXml,
+
+    #[serde(rename(deserialize = "ContinuationToken"), default)]
+    next_token: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct BucketsXml {
+    #[serde(rename(deserialize = "Bucket"), default)]
+    buckets: Vec<BucketXml>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BucketXml {
+    #[serde(rename(deserialize = "Name"))]
+    name: String,
+
+    #[serde(rename(deserialize = "CreationDate"))]
+    creation_date: String,
+}
+
+impl BucketInfo {
+    #[inline]
+    pub fn name(&self) -> &BucketName {
+        &self.name
+    }
+
+    #[inline]
+    pub fn creation_date(&self) -> OffsetDateTime {
+        self.creation_date
+    }
+}
+
+/// Parses a `ListAllMyBucketsResult` document.
+pub(crate) fn list_from_string(
+    s: impl AsRef<str>,
+) -> Result<TokenPage<BucketInfo>, InvalidMetadata> {
+    let result: ListAllMyBucketsResult =
+        serde_xml_rs::from_str(s.as_ref()).map_err(|_| InvalidMetadata::new())?;
+    Ok(TokenPage {
+        items: result
+            .buckets
+            .buckets
+            .into_iter()
+            .map(|bucket| {
+                Ok(BucketInfo {
+                    name: bucket.name.parse().map_err(|_| InvalidMetadata::new())?,
+                    creation_date: OffsetDateTime::parse(&bucket.creation_date, &Rfc3339)
+                        .map_err(|_| InvalidMetadata::new())?,
+                })
+            })
+            .collect::<Result<_, InvalidMetadata>>()?,
+        next_token: result.next_token,
+    })
+}
@@ -0,0 +1,195 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+use std::{fmt, str::FromStr};
+
+/// An additional checksum algorithm supported by S3.
+///
+/// S3 can verify uploads with one of these checksums in
+/// addition to the `Content-MD5` header and returns the
+/// checksums of objects in the `x-amz-checksum-*` headers.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChecksumAlgorithm {
+    Crc32,
+    Crc32c,
+    Sha1,
+    Sha256,
+}
+
+/// A possible error when parsing a checksum algorithm.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidChecksumAlgorithm {
+    _priv: (),
+}
+
+impl ChecksumAlgorithm {
+    /// All checksum algorithms.
+    pub const ALL: [ChecksumAlgorithm; 4] = [Self::Crc32, Self::Crc32c, Self::Sha1, Self::Sha256];
+
+    /// Returns the S3 name of the algorithm, e.g. `CRC32C`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Crc32 => "CRC32",
+            Self::Crc32c => "CRC32C",
+            Self::Sha1 => "SHA1",
+            Self::Sha256 => "SHA256",
+        }
+    }
+
+    /// Returns the name of the header that carries the
+    /// base64-encoded checksum, e.g. `x-amz-checksum-crc32c`.
+    pub fn header(&self) -> &'static str {
+        match self {
+            Self::Crc32 => "x-amz-checksum-crc32",
+            Self::Crc32c => "x-amz-checksum-crc32c",
+            Self::Sha1 => "x-amz-checksum-sha1",
+            Self::Sha256 => "x-amz-checksum-sha256",
+        }
+    }
+
+    /// Returns the base64-encoded checksum of `data`, as
+    /// sent and returned by S3.
+    ///
+    /// # Example
+    /// ```
+    /// use minio::s3::ChecksumAlgorithm;
+    ///
+    /// assert_eq!("aR2qLw==", ChecksumAlgorithm::Crc32c.checksum(b"Hello World"));
+    /// ```
+    pub fn checksum(&self, data: &[u8]) -> String {
+        let mut hasher = Hasher::new(*self);
+        hasher.update(data);
+        hasher.finalize()
+    }
+
+    /// Returns the length of the base64-encoded checksum.
+    pub(crate) fn encoded_len(&self) -> usize {
+        let len: usize = match self {
+            Self::Crc32 | Self::Crc32c => 4,
+            Self::Sha1 => 20,
+            Self::Sha256 => 32,
+        };
+        len.div_ceil(3) * 4
+    }
+}
+
+/// Computes a checksum incrementally.
+#[derive(Clone)]
+pub(crate) enum Hasher {
+    Crc32(u32),
+    Crc32c(u32),
+    Sha1(Sha1),
+    Sha256(Sha256),
+}
+
+impl Hasher {
+    pub(crate) fn new(algorithm: ChecksumAlgorithm) -> Self {
+        match algorithm {
+            ChecksumAlgorithm::Crc32 => Self::Crc32(0),
+            ChecksumAlgorithm::Crc32c => Self::Crc32c(0),
+            ChecksumAlgorithm::Sha1 => Self::Sha1(Sha1::new()),
+            ChecksumAlgorithm::Sha256 => Self::Sha256(Sha256::new()),
+        }
+    }
+
+    pub(crate) fn algorithm(&self) -> ChecksumAlgorithm {
+        match self {
+            Self::Crc32(_) => ChecksumAlgorithm::Crc32,
+            Self::Crc32c(_) => ChecksumAlgorithm::Crc32c,
+            Self::Sha1(_) => ChecksumAlgorithm::Sha1,
+            Self::Sha256(_) => ChecksumAlgorithm::Sha256,
+        }
+    }
+
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        match self {
+            Self::Crc32(crc) => *crc = crc32_update(&CRC32_TABLE, *crc, data),
+            Self::Crc32c(crc) => *crc = crc32_update(&CRC32C_TABLE, *crc, data),
+            Self::Sha1(h) => h.update(data),
+            Self::Sha256(h) => h.update(data),
+        }
+    }
+
+    /// Returns the base64-encoded checksum.
+    pub(crate) fn finalize(self) -> String {
+        match self {
+            Self::Crc32(crc) | Self::Crc32c(crc) => base64::encode(crc.to_be_bytes()),
+            Self::Sha1(h) => base64::encode(h.finalize()),
+            Self::Sha256(h) => base64::encode(h.finalize()),
+        }
+    }
+}
+
+/// The lookup tables of the reflected CRC-32 (IEEE) and
+/// CRC-32C (Castagnoli) polynomials.
+static CRC32_TABLE: [u32; 256] = crc32_table(0xEDB8_8320);
+static CRC32C_TABLE: [u32; 256] = crc32_table(0x82F6_3B78);
+
+const fn crc32_table(polynomial: u32) -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = match crc & 1 {
+                1 => (crc >> 1) ^ polynomial,
+                _ => crc >> 1,
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+fn crc32_update(table: &[u32; 256], crc: u32, data: &[u8]) -> u32 {
+    !data.iter().fold(!crc, |crc, b| {
+        table[((crc ^ *b as u32) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+impl fmt::Display for ChecksumAlgorithm {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ChecksumAlgorithm {
+    type Err = InvalidChecksumAlgorithm;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|algorithm| algorithm.as_str().eq_ignore_ascii_case(s))
+            .ok_or(InvalidChecksumAlgorithm { _priv: () })
+    }
+}
+
+// === InvalidChecksumAlgorithm ===
+
+impl std::error::Error for InvalidChecksumAlgorithm {}
+
+impl fmt::Display for InvalidChecksumAlgorithm {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("invalid checksum algorithm")
+    }
+}
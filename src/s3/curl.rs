@@ -0,0 +1,163 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::{
+    pool::ConnectionPool,
+    proxy::Proxy,
+    tls::Tls,
+    transport::{async_trait, Transport},
+};
+use async_std::io::BufReader;
+use isahc::{
+    auth::{Authentication, Credentials},
+    config::{CaCertificate, Configurable, SslOption},
+    http,
+};
+use std::time::Duration;
+use surf::http::{Body, Request, Response, Result};
+
+/// The curl-based HTTP client used to send S3 requests.
+///
+/// It is equivalent to the default surf client, except that it
+/// sends requests with an empty body without any body at all.
+/// Otherwise, curl treats a `HEAD` request as custom request and
+/// waits for a response body of the announced `Content-Length`,
+/// which never arrives.
+#[derive(Debug, Clone)]
+pub(crate) struct CurlClient {
+    pool: ConnectionPool,
+    connect_timeout: Option<Duration>,
+    proxy: Option<Proxy>,
+    tls: Tls,
+}
+
+impl CurlClient {
+    /// Returns a client that shares its connections with
+    /// all other clients using the shared pool.
+    pub(crate) fn new() -> Self {
+        Self {
+            pool: ConnectionPool::shared(),
+            connect_timeout: None,
+            proxy: None,
+            tls: Tls::new(),
+        }
+    }
+
+    /// Sets the pool of connections requests are sent over.
+    pub(crate) fn with_pool(mut self, pool: ConnectionPool) -> Self {
+        self.pool = pool;
+        self
+    }
+
+    /// Sets the proxy requests are sent via. Without a proxy,
+    /// the proxy environment variables apply.
+    pub(crate) fn with_proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn proxy(&self) -> Option<&Proxy> {
+        self.proxy.as_ref()
+    }
+
+    /// Sets the TLS settings of `https` requests.
+    pub(crate) fn with_tls(mut self, tls: Tls) -> Self {
+        self.tls = tls;
+        self
+    }
+
+    #[inline]
+    pub(crate) fn tls(&self) -> &Tls {
+        &self.tls
+    }
+
+    #[inline]
+    pub(crate) fn pool(&self) -> &ConnectionPool {
+        &self.pool
+    }
+
+    /// Sets the time it may take to establish a connection.
+    pub(crate) fn with_connect_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+}
+
+#[async_trait]
+impl Transport for CurlClient {
+    async fn send(&self, mut request: Request) -> Result<Response> {
+        let mut builder = http::Request::builder()
+            .uri(request.url().as_str())
+            .method(request.method().as_ref());
+        for (name, values) in request.iter() {
+            for value in values {
+                builder = builder.header(name.as_str(), value.as_str());
+            }
+        }
+
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+
+        if request.url().scheme() == "https" {
+            if let Some(bundle) = self.tls.root_bundle()? {
+                builder = builder.ssl_ca_certificate(CaCertificate::file(bundle));
+            }
+            if let Some(identity) = self.tls.identity() {
+                builder = builder.ssl_client_certificate(identity.to_client_certificate());
+            }
+            if self.tls.insecure_skip_verify() {
+                builder = builder.ssl_options(
+                    SslOption::DANGER_ACCEPT_INVALID_CERTS | SslOption::DANGER_ACCEPT_INVALID_HOSTS,
+                );
+            }
+        }
+
+        let proxy = match &self.proxy {
+            Some(proxy) => Some(proxy.clone()).filter(|proxy| proxy.applies_to(request.url())),
+            None => Proxy::from_env(request.url()),
+        };
+        builder = match proxy {
+            Some(proxy) => {
+                let url = proxy.url().expect("proxy with URL");
+                builder = builder.proxy(Some(url.as_str().parse::<http::Uri>()?));
+                match (proxy.username(), proxy.password()) {
+                    (Some(username), Some(password)) => builder
+                        .proxy_authentication(Authentication::basic())
+                        .proxy_credentials(Credentials::new(username, password)),
+                    _ => builder,
+                }
+            }
+            None => builder.proxy(None),
+        };
+
+        let body = request.take_body();
+        let body = match body.len() {
+            Some(0) => isahc::Body::empty(),
+            Some(len) => isahc::Body::from_reader_sized(body, len as u64),
+            None => isahc::Body::from_reader(body),
+        };
+        let request = builder.body(body)?;
+
+        let (parts, body) = self.pool.client()?.send_async(request).await?.into_parts();
+        let mut response = Response::new(parts.status.as_u16());
+        for (name, value) in &parts.headers {
+            response.append_header(name.as_str(), value.to_str()?);
+        }
+        response.set_body(Body::from_reader(BufReader::new(body), None));
+        Ok(response)
+    }
+}
@@ -13,15 +13,30 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::s3::InvalidMetadata;
+use crate::s3::{
+    provider::CredentialsError,
+    sv4::{Signature, SigningError},
+    CircuitOpen, IntegrityError, InvalidBucketName, InvalidMetadata, InvalidObjectKey, RetryPolicy,
+};
 use serde_derive::Deserialize;
-use std::{convert::Infallible, fmt};
-use surf::http::url;
+use std::{convert::Infallible, fmt, io};
+use surf::{http::url, Response, StatusCode};
 
 /// A generic S3 error.
 #[derive(Debug)]
 pub struct Error {
     inner: ErrorKind,
+    response: Option<Box<ResponseDetails>>,
+}
+
+/// The error response of a failed request.
+#[derive(Debug, Default)]
+struct ResponseDetails {
+    status: Option<StatusCode>,
+    request_id: Option<String>,
+    host_id: Option<String>,
+    resource: Option<String>,
+    body: Option<String>,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -31,20 +46,80 @@ pub enum ErrorCode {
     BucketAlreadyExists,
     BucketAlreadyOwnedByYou,
     BucketNotEmpty,
+    ExpiredToken,
+    InternalError,
+    NoSuchBucket,
+    NoSuchBucketPolicy,
+    NoSuchKey,
+    NoSuchTagSet,
+    ObjectLockConfigurationNotFoundError,
+    RequestTimeout,
+    ServiceUnavailable,
+    SignatureDoesNotMatch,
+    SlowDown,
 
     #[doc(hidden)]
     Undefined,
 }
 
+/// The kind of an [`Error`], with the underlying error, if any.
+///
+/// New kinds may be added in future versions.
+///
+/// See: [`Error::kind`]
 #[derive(Debug)]
-enum ErrorKind {
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The request could not be sent or its response
+    /// could not be read.
     Http(surf::Error),
 
+    /// A local file, reader or writer failed, e.g. the source
+    /// of an upload or the destination of a download.
+    Io(io::Error),
+
+    /// An argument is invalid. It is detected before any
+    /// request is sent.
+    InvalidInput(&'static str),
+
+    /// A URL could not be built for the request.
     Url(url::ParseError),
 
+    /// A value could not be converted, e.g. into a header
+    /// value, or a request could not be signed.
+    Convert(Box<dyn std::error::Error + Send + Sync>),
+
+    /// The response contained invalid metadata.
     Metadata(InvalidMetadata),
 
+    /// An object key is invalid.
+    Key(InvalidObjectKey),
+
+    /// A bucket name is invalid.
+    Bucket(InvalidBucketName),
+
+    /// The request has been rejected because the circuit
+    /// of the S3 endpoint is open.
+    Circuit(CircuitOpen),
+
+    /// Downloaded content does not match the ETag of the object.
+    Integrity(IntegrityError),
+
+    /// No credentials could be provided.
+    Credentials(CredentialsError),
+
+    /// The S3 endpoint did not respond in time.
+    Timeout,
+
+    /// The request has been cancelled.
+    Cancelled,
+
+    /// S3 responded with an error code and message.
     S3(ErrorCode, String),
+
+    /// S3 rejected the signature of the request, with the
+    /// message and the signature computed by the client.
+    Signature(String, Box<Signature>),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -57,32 +132,305 @@ struct ErrorResponse {
 
     #[serde(rename(deserialize = "Message"))]
     message: String,
+
+    #[serde(rename(deserialize = "Resource"), default)]
+    resource: Option<String>,
+
+    #[serde(rename(deserialize = "RequestId"), default)]
+    request_id: Option<String>,
+
+    #[serde(rename(deserialize = "HostId"), default)]
+    host_id: Option<String>,
 }
 
+/// Returns the error for the body of an error response.
+///
+/// The body is kept as the raw body of the error.
 pub(crate) fn from_string(s: impl AsRef<str>) -> Error {
-    let result: Result<ErrorResponse, serde_xml_rs::Error> = serde_xml_rs::from_str(s.as_ref());
+    let body = s.as_ref();
+    let result: Result<ErrorResponse, serde_xml_rs::Error> = serde_xml_rs::from_str(body);
+    let mut details = ResponseDetails {
+        body: Some(String::from(body)),
+        ..ResponseDetails::default()
+    };
     let error = match result {
-        Ok(response) => match response.code.parse::<ErrorCode>() {
-            Ok(code) => ErrorKind::S3(code, response.message),
-            Err(error) => ErrorKind::S3(ErrorCode::Undefined, error.to_string()),
-        },
+        Ok(response) => {
+            let non_empty = |value: Option<String>| value.filter(|value| !value.is_empty());
+            details.resource = non_empty(response.resource);
+            details.request_id = non_empty(response.request_id);
+            details.host_id = non_empty(response.host_id);
+            match response.code.parse::<ErrorCode>() {
+                Ok(code) => ErrorKind::S3(code, response.message),
+                Err(error) => ErrorKind::S3(ErrorCode::Undefined, error.to_string()),
+            }
+        }
         Err(why) => ErrorKind::S3(ErrorCode::Undefined, why.to_string()),
     };
-    Error { inner: error }
+    Error {
+        inner: error,
+        response: Some(Box::new(details)),
+    }
+}
+
+/// Reads the body of an error response and returns the error
+/// it describes, or the error that occurred reading the body.
+pub(crate) async fn from_response(response: &mut Response) -> Error {
+    match response.body_string().await {
+        Ok(body) => from_body(response, body),
+        Err(err) => err.into(),
+    }
+}
+
+/// Returns the error for an error response whose body has
+/// already been read.
+///
+/// The status and request IDs of the response are attached.
+pub(crate) fn from_body(response: &Response, body: impl AsRef<str>) -> Error {
+    with_response(from_string(body), response)
+}
+
+/// Attaches the status and the request IDs of the response
+/// to the error. Request IDs in its headers take precedence
+/// over the ones in its body.
+fn with_response(mut error: Error, response: &Response) -> Error {
+    let header = |name: &str| {
+        response
+            .header(name)
+            .map(|value| String::from(value.as_str()))
+    };
+    let details = error.response.get_or_insert_with(Box::default);
+    details.status = Some(response.status());
+    if let Some(request_id) = header("x-amz-request-id") {
+        details.request_id = Some(request_id);
+    }
+    if let Some(host_id) = header("x-amz-id-2") {
+        details.host_id = Some(host_id);
+    }
+    error
+}
+
+/// Attaches the locally computed signature to a
+/// `SignatureDoesNotMatch` error. Other errors are
+/// returned unchanged.
+pub(crate) fn with_signature(error: Error, signature: Signature) -> Error {
+    match error.inner {
+        ErrorKind::S3(ErrorCode::SignatureDoesNotMatch, message) => Error {
+            inner: ErrorKind::Signature(message, Box::new(signature)),
+            response: error.response,
+        },
+        inner => Error {
+            inner,
+            response: error.response,
+        },
+    }
+}
+
+/// Returns the error for a response without body, like
+/// the response of a `HEAD` request.
+///
+/// A `404 Not Found` is reported as `NoSuchKey` since a
+/// missing bucket is reported by its own `HEAD` request.
+pub(crate) fn from_status(response: &Response) -> Error {
+    let status = response.status();
+    let code = match status {
+        StatusCode::Forbidden => ErrorCode::AccessDenied,
+        StatusCode::NotFound => ErrorCode::NoSuchKey,
+        _ => ErrorCode::Undefined,
+    };
+    let error = Error::from(ErrorKind::S3(code, String::from(status.canonical_reason())));
+    with_response(error, response)
+}
+
+pub(crate) fn timeout() -> Error {
+    Error::from(ErrorKind::Timeout)
+}
+
+pub(crate) fn cancelled() -> Error {
+    Error::from(ErrorKind::Cancelled)
 }
 
 // === Error ===
 
 impl Error {
+    /// Returns the kind of the error.
+    ///
+    /// # Example
+    /// ```
+    /// use minio::s3::{Error, ErrorKind, ObjectKey};
+    ///
+    /// let err = Error::from("".parse::<ObjectKey>().unwrap_err());
+    /// assert!(matches!(err.kind(), ErrorKind::Key(_)));
+    /// ```
+    #[inline]
+    pub fn kind(&self) -> &ErrorKind {
+        &self.inner
+    }
+
     pub fn code(&self) -> Option<ErrorCode> {
         match self.inner {
             ErrorKind::S3(code, ..) => Some(code),
+            ErrorKind::Signature(..) => Some(ErrorCode::SignatureDoesNotMatch),
             _ => None,
         }
     }
+
+    /// Returns the signature computed by the client for a request
+    /// that has been rejected with `SignatureDoesNotMatch`.
+    ///
+    /// It is only available when signature debugging is enabled.
+    ///
+    /// See: [`Client::with_signature_debugging`](crate::s3::Client::with_signature_debugging)
+    pub fn signature(&self) -> Option<&Signature> {
+        match self.inner {
+            ErrorKind::Signature(_, ref signature) => Some(signature),
+            _ => None,
+        }
+    }
+
+    /// Returns the integrity error of a download whose content
+    /// does not match the ETag of the object.
+    ///
+    /// See: [`GetObjectOptions::with_verify_etag`](crate::s3::GetObjectOptions::with_verify_etag)
+    pub fn integrity(&self) -> Option<&IntegrityError> {
+        match self.inner {
+            ErrorKind::Integrity(ref err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// Returns the HTTP status code of the error response, if
+    /// the request failed with one.
+    pub fn status(&self) -> Option<StatusCode> {
+        self.response.as_ref().and_then(|response| response.status)
+    }
+
+    /// Returns the ID S3 assigned to the failed request, from the
+    /// `x-amz-request-id` header or the `RequestId` element of the
+    /// error response.
+    ///
+    /// Together with the [host ID](Error::host_id), it identifies
+    /// the request in server-side logs and support cases.
+    pub fn request_id(&self) -> Option<&str> {
+        self.response.as_ref()?.request_id.as_deref()
+    }
+
+    /// Returns the extended request ID of the failed request, from
+    /// the `x-amz-id-2` header or the `HostId` element of the error
+    /// response.
+    pub fn host_id(&self) -> Option<&str> {
+        self.response.as_ref()?.host_id.as_deref()
+    }
+
+    /// Returns the bucket or object the error applies to, from
+    /// the `Resource` element of the error response.
+    pub fn resource(&self) -> Option<&str> {
+        self.response.as_ref()?.resource.as_deref()
+    }
+
+    /// Returns the raw body of the error response, usually an
+    /// XML document.
+    ///
+    /// Responses without body, like the responses to `HEAD`
+    /// requests, have none.
+    pub fn body(&self) -> Option<&str> {
+        self.response.as_ref()?.body.as_deref()
+    }
+
+    /// Reports whether the request failed because the object,
+    /// or the bucket, does not exist.
+    pub fn is_not_found(&self) -> bool {
+        matches!(
+            self.code(),
+            Some(ErrorCode::NoSuchKey | ErrorCode::NoSuchBucket)
+        )
+    }
+
+    /// Reports whether the request failed with a transient error,
+    /// such that sending it again may succeed.
+    ///
+    /// Transport errors, timeouts, `5xx` and `429 Too Many Requests`
+    /// responses and the S3 errors `InternalError`, `RequestTimeout`,
+    /// `ServiceUnavailable` and `SlowDown` are transient. All other
+    /// errors, like invalid arguments, failing local files, missing
+    /// objects or cancelled requests, are permanent.
+    ///
+    /// The client's [`RetryPolicy`](crate::s3::RetryPolicy) retries
+    /// requests that fail with transient errors.
+    pub fn is_retryable(&self) -> bool {
+        match self.inner {
+            ErrorKind::Http(_) | ErrorKind::Timeout => true,
+            ErrorKind::S3(code, _) => {
+                matches!(
+                    code,
+                    ErrorCode::InternalError
+                        | ErrorCode::RequestTimeout
+                        | ErrorCode::ServiceUnavailable
+                        | ErrorCode::SlowDown
+                ) || self.status().is_some_and(RetryPolicy::is_retryable)
+            }
+            _ => false,
+        }
+    }
+
+    /// Reports whether the request has been rejected because
+    /// the circuit of the S3 endpoint is open.
+    ///
+    /// See: [`CircuitBreaker`](crate::s3::CircuitBreaker)
+    pub fn is_circuit_open(&self) -> bool {
+        matches!(self.inner, ErrorKind::Circuit(_))
+    }
+
+    /// Reports whether the request has been aborted because
+    /// the S3 endpoint did not respond in time.
+    pub fn is_timeout(&self) -> bool {
+        matches!(self.inner, ErrorKind::Timeout)
+    }
+
+    /// Reports whether the request has been aborted because
+    /// it has been cancelled.
+    ///
+    /// See: [`CancellationToken`](crate::s3::CancellationToken)
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self.inner, ErrorKind::Cancelled)
+    }
+
+    /// Reports whether the request failed because the S3
+    /// endpoint could not be reached.
+    ///
+    /// Local I/O errors and invalid arguments say nothing about
+    /// the health of the endpoint and are not transport errors.
+    pub(crate) fn is_transport(&self) -> bool {
+        matches!(self.inner, ErrorKind::Http(_))
+    }
 }
 
-impl std::error::Error for Error {}
+/// The underlying error of a failed conversion, transport error
+/// or invalid value is the source of the error. The error itself
+/// only describes what failed, such that reporters that print
+/// the whole chain of sources do not repeat messages. The errors
+/// of S3 responses, invalid arguments, timeouts and cancellations
+/// have no source.
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use self::ErrorKind::*;
+        match self.inner {
+            Http(ref err) => {
+                let err: &(dyn std::error::Error + Send + Sync) = err.as_ref();
+                Some(err)
+            }
+            Io(ref err) => Some(err),
+            Url(ref err) => Some(err),
+            Convert(ref err) => Some(err.as_ref()),
+            Metadata(ref err) => Some(err),
+            Key(ref err) => Some(err),
+            Bucket(ref err) => Some(err),
+            Circuit(ref err) => Some(err),
+            Integrity(ref err) => Some(err),
+            Credentials(ref err) => Some(err),
+            InvalidInput(_) | Timeout | Cancelled | S3(..) | Signature(..) => None,
+        }
+    }
+}
 
 impl From<Infallible> for Error {
     fn from(err: Infallible) -> Self {
@@ -97,6 +445,7 @@ where
     fn from(value: T) -> Self {
         Self {
             inner: ErrorKind::from(value),
+            response: None,
         }
     }
 }
@@ -105,10 +454,27 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use self::ErrorKind::*;
         match self.inner {
-            Http(ref err) => fmt::Display::fmt(err, f),
-            Url(ref err) => fmt::Display::fmt(err, f),
-            Metadata(ref err) => fmt::Display::fmt(err, f),
+            Http(_) => f.write_str("S3 transport error"),
+            Io(_) => f.write_str("local I/O error"),
+            InvalidInput(msg) => f.write_str(msg),
+            Url(_) => f.write_str("invalid S3 request URL"),
+            Convert(_) => f.write_str("cannot build S3 request"),
+            Metadata(_) => f.write_str("invalid S3 response metadata"),
+            Key(_) => f.write_str("invalid S3 object key"),
+            Bucket(_) => f.write_str("invalid S3 bucket name"),
+            Circuit(_) => f.write_str("S3 endpoint unavailable"),
+            Integrity(_) => f.write_str("S3 download integrity check failed"),
+            Credentials(_) => f.write_str("S3 credentials unavailable"),
+            Timeout => f.write_str("S3 request timed out"),
+            Cancelled => f.write_str("S3 request cancelled"),
             S3(code, ref msg) => write!(f, "{}: {}", code, msg),
+            Signature(ref msg, ref signature) => write!(
+                f,
+                "{}: {}\n\n{}",
+                ErrorCode::SignatureDoesNotMatch,
+                msg,
+                signature
+            ),
         }
     }
 }
@@ -121,18 +487,85 @@ impl From<InvalidMetadata> for ErrorKind {
     }
 }
 
+impl From<InvalidObjectKey> for ErrorKind {
+    fn from(err: InvalidObjectKey) -> Self {
+        Self::Key(err)
+    }
+}
+
+impl From<InvalidBucketName> for ErrorKind {
+    fn from(err: InvalidBucketName) -> Self {
+        Self::Bucket(err)
+    }
+}
+
+impl From<CircuitOpen> for ErrorKind {
+    fn from(err: CircuitOpen) -> Self {
+        Self::Circuit(err)
+    }
+}
+
+impl From<IntegrityError> for ErrorKind {
+    fn from(err: IntegrityError) -> Self {
+        Self::Integrity(err)
+    }
+}
+
+impl From<CredentialsError> for ErrorKind {
+    fn from(err: CredentialsError) -> Self {
+        Self::Credentials(err)
+    }
+}
+
 impl From<surf::Error> for ErrorKind {
     fn from(err: surf::Error) -> Self {
         Self::Http(err)
     }
 }
 
+impl From<io::Error> for ErrorKind {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
 impl From<url::ParseError> for ErrorKind {
     fn from(err: url::ParseError) -> Self {
         Self::Url(err)
     }
 }
 
+impl From<http::Error> for ErrorKind {
+    fn from(err: http::Error) -> Self {
+        Self::Convert(Box::new(err))
+    }
+}
+
+impl From<http::method::InvalidMethod> for ErrorKind {
+    fn from(err: http::method::InvalidMethod) -> Self {
+        Self::Convert(Box::new(err))
+    }
+}
+
+impl From<http::header::ToStrError> for ErrorKind {
+    fn from(err: http::header::ToStrError) -> Self {
+        Self::Convert(Box::new(err))
+    }
+}
+
+#[cfg(feature = "aws-credential-types")]
+impl From<aws_credential_types::provider::error::CredentialsError> for ErrorKind {
+    fn from(err: aws_credential_types::provider::error::CredentialsError) -> Self {
+        Self::Convert(Box::new(err))
+    }
+}
+
+impl From<SigningError> for ErrorKind {
+    fn from(err: SigningError) -> Self {
+        Self::Convert(Box::new(err))
+    }
+}
+
 impl From<UnknownErrorCode> for ErrorKind {
     fn from(err: UnknownErrorCode) -> Self {
         Self::S3(ErrorCode::Undefined, err.0)
@@ -151,6 +584,17 @@ impl std::str::FromStr for ErrorCode {
             "BucketAlreadyExists" => Ok(BucketAlreadyExists),
             "BucketAlreadyOwnedByYou" => Ok(BucketAlreadyOwnedByYou),
             "BucketNotEmpty" => Ok(BucketNotEmpty),
+            "ExpiredToken" => Ok(ExpiredToken),
+            "InternalError" => Ok(InternalError),
+            "NoSuchBucket" => Ok(NoSuchBucket),
+            "NoSuchBucketPolicy" => Ok(NoSuchBucketPolicy),
+            "NoSuchKey" => Ok(NoSuchKey),
+            "NoSuchTagSet" => Ok(NoSuchTagSet),
+            "ObjectLockConfigurationNotFoundError" => Ok(ObjectLockConfigurationNotFoundError),
+            "RequestTimeout" => Ok(RequestTimeout),
+            "ServiceUnavailable" => Ok(ServiceUnavailable),
+            "SignatureDoesNotMatch" => Ok(SignatureDoesNotMatch),
+            "SlowDown" => Ok(SlowDown),
             _ => Err(UnknownErrorCode(String::from(s))),
         }
     }
@@ -164,6 +608,17 @@ impl fmt::Display for ErrorCode {
             BucketAlreadyExists => "BucketAlreadyExists",
             BucketAlreadyOwnedByYou => "BucketAlreadyOwnedByYou",
             BucketNotEmpty => "BucketNotEmpty",
+            ExpiredToken => "ExpiredToken",
+            InternalError => "InternalError",
+            NoSuchBucket => "NoSuchBucket",
+            NoSuchBucketPolicy => "NoSuchBucketPolicy",
+            NoSuchKey => "NoSuchKey",
+            NoSuchTagSet => "NoSuchTagSet",
+            ObjectLockConfigurationNotFoundError => "ObjectLockConfigurationNotFoundError",
+            RequestTimeout => "RequestTimeout",
+            ServiceUnavailable => "ServiceUnavailable",
+            SignatureDoesNotMatch => "SignatureDoesNotMatch",
+            SlowDown => "SlowDown",
 
             Undefined => "Undefined",
         };
@@ -14,7 +14,7 @@
 // limitations under the License.
 
 extern crate hex;
-use async_std::io::{Read as AsyncRead, Write as AsyncWrite};
+use async_std::io::{Read as AsyncRead, ReadExt, Write as AsyncWrite};
 use md5::Digest;
 use std::{
     convert::TryFrom,
@@ -80,6 +80,27 @@ pub struct InvalidEtag {
     _priv: (),
 }
 
+/// The error returned when downloaded content does not
+/// match the ETag of the object.
+///
+/// The ETag of a single-part object that is not encrypted
+/// with SSE-C or SSE-KMS is the MD5 sum of its content.
+/// Hence, a mismatch indicates that the content has been
+/// corrupted in transit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntegrityError {
+    expected: Etag,
+    actual: Etag,
+}
+
+/// Computes the MD5 sum of content while it is being
+/// read and compares it against an expected `Etag`.
+#[derive(Clone)]
+pub(crate) struct Verifier {
+    hasher: md5::Md5,
+    expected: Etag,
+}
+
 /// Wrapper around an md5::MD5 implementing async io::Write.
 struct Md5(md5::Md5);
 
@@ -114,6 +135,71 @@ impl Etag {
         })
     }
 
+    /// Computes the multi-part `Etag` of the data returned from
+    /// the reader, as if it had been uploaded in parts of
+    /// `part_size` bytes.
+    ///
+    /// Continuously reads data from `reader`, until `EOF`, and
+    /// computes the `Etag` as MD5 sum of the concatenated MD5 sums
+    /// of all parts, followed by the `-N` part suffix. It allows
+    /// checking whether local data matches a multi-part object,
+    /// provided the part size of the upload is known.
+    ///
+    /// Empty data is computed as a single empty part. It fails
+    /// with [`InvalidInput`](io::ErrorKind::InvalidInput) if
+    /// `part_size` is `0` or the data consists of more than `10000`
+    /// parts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minio::s3::Etag;
+    /// use async_std::{task, io::Cursor};
+    ///
+    /// let mut reader = Cursor::new("Hello World");
+    /// let etag = task::block_on(Etag::compute_multipart(&mut reader, 6)).unwrap();
+    ///
+    /// assert_eq!("f5caab02bb8180e6e93a9ebd342462db-2".parse::<Etag>().unwrap(), etag);
+    /// ```
+    pub async fn compute_multipart<R>(reader: &mut R, part_size: u64) -> io::Result<Etag>
+    where
+        R: AsyncRead + Unpin + ?Sized,
+    {
+        if part_size == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "part size must not be 0",
+            ));
+        }
+
+        let mut etags = md5::Md5::new();
+        let mut parts: u16 = 0;
+        loop {
+            let mut h = Md5::from(md5::Md5::new());
+            let n = async_std::io::copy(&mut reader.take(part_size), &mut h).await?;
+            if n == 0 && parts > 0 {
+                break;
+            }
+            if parts == 10000 {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "data consists of more than 10000 parts",
+                ));
+            }
+
+            let h: md5::Md5 = h.into();
+            etags.update(h.finalize());
+            parts += 1;
+            if n < part_size {
+                break;
+            }
+        }
+        Ok(Etag {
+            bytes: etags.finalize().into(),
+            parts: Some(parts),
+        })
+    }
+
     /// Computes the `Etag` of the data returned from the reader.
     ///
     /// Continuously reads data from `reader`, until `EOF`, and
@@ -161,6 +247,25 @@ impl Etag {
         }
     }
 
+    /// Returns the base64-encoded MD5 sum of the `Etag`, as
+    /// sent in the `Content-MD5` header.
+    ///
+    /// It is only the MD5 sum of the content for single-part
+    /// `Etag`s computed from the content.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minio::s3::Etag;
+    ///
+    /// let etag = Etag::compute_from("Hello World");
+    /// assert_eq!("sQqNsWTgdUEFt6mb5y4/5Q==", etag.content_md5());
+    /// ```
+    #[inline]
+    pub fn content_md5(&self) -> String {
+        base64::encode(self.bytes)
+    }
+
     /// Returns `Some` number of parts in case of a multi-part `Etag` or
     /// `None` for single-part `Etag`s.
     ///
@@ -181,10 +286,43 @@ impl Etag {
     }
 }
 
+/// Formats the `Etag` as 32 lowercase hex characters,
+/// followed by the `-N` part suffix for multi-part ETags.
+///
+/// The output is the canonical representation of the `Etag`
+/// and can be parsed again.
+///
+/// # Example
+///
+/// ```
+/// use minio::s3::Etag;
+///
+/// let etag = "\"0A0B0C0D0E0F00102030405060708090-3\"".parse::<Etag>().unwrap();
+/// assert_eq!("0a0b0c0d0e0f00102030405060708090-3", etag.to_string());
+/// ```
 impl fmt::Display for Etag {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <Self as fmt::LowerHex>::fmt(self, f)
+    }
+}
+
+impl fmt::LowerHex for Etag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in &self.bytes {
+            write!(f, "{:02x}", byte)?;
+        }
+        match self.parts {
+            Some(n) => write!(f, "-{}", n),
+            None => Ok(()),
+        }
+    }
+}
+
+impl fmt::UpperHex for Etag {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for byte in &self.bytes {
-            write!(f, "{:x}", byte)?;
+            write!(f, "{:02X}", byte)?;
         }
         match self.parts {
             Some(n) => write!(f, "-{}", n),
@@ -196,7 +334,7 @@ impl fmt::Display for Etag {
 impl fmt::Debug for Etag {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        <Self as fmt::Display>::fmt(&self, f)
+        <Self as fmt::Display>::fmt(self, f)
     }
 }
 
@@ -264,6 +402,67 @@ impl<'a> TryFrom<&'a String> for Etag {
     }
 }
 
+// === IntegrityError ===
+
+impl IntegrityError {
+    /// Returns the ETag reported by the server.
+    #[inline]
+    pub fn expected(&self) -> &Etag {
+        &self.expected
+    }
+
+    /// Returns the MD5 sum of the received content.
+    #[inline]
+    pub fn actual(&self) -> &Etag {
+        &self.actual
+    }
+}
+
+impl std::error::Error for IntegrityError {}
+
+impl fmt::Display for IntegrityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "integrity check failed: expected ETag {} but content has MD5 {}",
+            self.expected, self.actual
+        )
+    }
+}
+
+// === Verifier ===
+
+impl Verifier {
+    pub(crate) fn new(expected: Etag) -> Self {
+        Self {
+            hasher: md5::Md5::new(),
+            expected,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn update(&mut self, bytes: &[u8]) {
+        self.hasher.update(bytes);
+    }
+
+    /// Compares the MD5 sum of the content read so far
+    /// against the expected `Etag`.
+    pub(crate) fn verify(&self) -> Result<(), IntegrityError> {
+        let actual = Etag {
+            bytes: self.hasher.clone().finalize().into(),
+            parts: None,
+        };
+        if actual == self.expected {
+            Ok(())
+        } else {
+            Err(IntegrityError {
+                expected: self.expected,
+                actual,
+            })
+        }
+    }
+}
+
 // === InvalidEtag ===
 
 impl InvalidEtag {
@@ -302,9 +501,9 @@ impl From<md5::Md5> for Md5 {
     }
 }
 
-impl Into<md5::Md5> for Md5 {
-    fn into(self) -> md5::Md5 {
-        self.0
+impl From<Md5> for md5::Md5 {
+    fn from(h: Md5) -> Self {
+        h.0
     }
 }
 
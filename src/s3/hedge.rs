@@ -0,0 +1,91 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::transport::Transport;
+use async_std::future;
+use futures::future::{select, Either};
+use std::time::Duration;
+use surf::http::{Method, Request, Response};
+
+/// A hedging policy for idempotent read requests.
+///
+/// When a `GET` or `HEAD` request has not produced a response
+/// within the hedging delay, a second identical request is sent
+/// and whichever response arrives first is used. This trades a
+/// small amount of additional load for a lower tail latency when
+/// individual S3 nodes respond slowly.
+///
+/// # Example
+/// ```
+/// use minio::s3::Hedging;
+/// use std::time::Duration;
+///
+/// let hedging = Hedging::new(Duration::from_millis(250));
+/// assert_eq!(Duration::from_millis(250), hedging.delay());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hedging {
+    delay: Duration,
+}
+
+impl Hedging {
+    /// Returns a new hedging policy that sends a second request
+    /// once the first one has not completed within `delay`.
+    #[inline]
+    pub fn new(delay: Duration) -> Self {
+        Self { delay }
+    }
+
+    /// Returns the latency threshold after which a request
+    /// gets hedged.
+    #[inline]
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// Reports whether requests with the given method may be
+    /// hedged. Only idempotent reads are sent more than once.
+    #[inline]
+    pub(crate) fn applies_to(&self, method: Method) -> bool {
+        matches!(method, Method::Get | Method::Head)
+    }
+
+    /// Sends the request and, if it has not completed within the
+    /// hedging delay, races it against an identical second request.
+    ///
+    /// A transport error of one request is only returned when the
+    /// other request fails as well.
+    pub(crate) async fn send(
+        &self,
+        transport: &dyn Transport,
+        request: Request,
+    ) -> surf::Result<Response> {
+        let hedge = request.clone();
+
+        let first = transport.send(request);
+        futures::pin_mut!(first);
+        if let Ok(response) = future::timeout(self.delay, &mut first).await {
+            return response;
+        }
+
+        let second = transport.send(hedge);
+        futures::pin_mut!(second);
+        match select(first, second).await {
+            Either::Left((Ok(response), _)) | Either::Right((Ok(response), _)) => Ok(response),
+            Either::Left((Err(_), other)) => other.await,
+            Either::Right((Err(_), other)) => other.await,
+        }
+    }
+}
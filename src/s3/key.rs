@@ -0,0 +1,183 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::{convert::TryFrom, fmt, ops::Deref, str::FromStr};
+
+/// A valid S3 object key.
+///
+/// An object key is a non-empty UTF-8 string of at most
+/// 1024 bytes. Keys are kept exactly as given, such that a key
+/// returned by a listing always refers to the listed object.
+///
+/// Keys containing control characters or `.` resp. `..` path
+/// segments are rejected. Such keys are either not supported by
+/// S3 implementations, like MinIO, or cannot be sent as part of
+/// a URL, which would resolve the segments to a different key.
+///
+/// All APIs that take an object key accept strings as well and
+/// reject invalid keys before sending any request.
+///
+/// # Example
+/// ```
+/// use minio::s3::ObjectKey;
+///
+/// let key: ObjectKey = "photos//2022\\beach.jpg".parse().unwrap();
+/// assert_eq!("photos//2022\\beach.jpg", key.as_str());
+///
+/// assert!("".parse::<ObjectKey>().is_err());
+/// assert!("photos/../secret".parse::<ObjectKey>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ObjectKey(String);
+
+/// A possible error when parsing an S3 object key.
+#[derive(Clone, PartialEq, Eq)]
+pub struct InvalidObjectKey {
+    reason: &'static str,
+}
+
+impl ObjectKey {
+    /// The maximum length of an object key, in bytes.
+    pub const MAX_LEN: usize = 1024;
+
+    /// Returns a new `ObjectKey` from the key string.
+    pub fn new(key: impl AsRef<str>) -> Result<Self, InvalidObjectKey> {
+        let key = key.as_ref();
+        if key.is_empty() {
+            return Err(InvalidObjectKey::new("key is empty"));
+        }
+        if key.len() > Self::MAX_LEN {
+            return Err(InvalidObjectKey::new("key is longer than 1024 bytes"));
+        }
+        if key.chars().any(char::is_control) {
+            return Err(InvalidObjectKey::new("key contains control characters"));
+        }
+        if key.split('/').any(|segment| matches!(segment, "." | "..")) {
+            return Err(InvalidObjectKey::new("key contains '.' or '..' segments"));
+        }
+        Ok(Self(String::from(key)))
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    #[inline]
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for ObjectKey {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for ObjectKey {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for ObjectKey {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for ObjectKey {
+    type Err = InvalidObjectKey;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for ObjectKey {
+    type Error = InvalidObjectKey;
+
+    #[inline]
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        Self::new(s)
+    }
+}
+
+impl<'a> TryFrom<&'a String> for ObjectKey {
+    type Error = InvalidObjectKey;
+
+    #[inline]
+    fn try_from(s: &'a String) -> Result<Self, Self::Error> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<String> for ObjectKey {
+    type Error = InvalidObjectKey;
+
+    #[inline]
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::new(s)
+    }
+}
+
+impl<'a> TryFrom<&'a ObjectKey> for ObjectKey {
+    type Error = InvalidObjectKey;
+
+    #[inline]
+    fn try_from(key: &'a ObjectKey) -> Result<Self, Self::Error> {
+        Ok(key.clone())
+    }
+}
+
+impl From<ObjectKey> for String {
+    #[inline]
+    fn from(key: ObjectKey) -> Self {
+        key.0
+    }
+}
+
+// === InvalidObjectKey ===
+
+impl InvalidObjectKey {
+    fn new(reason: &'static str) -> Self {
+        Self { reason }
+    }
+}
+
+impl std::error::Error for InvalidObjectKey {}
+
+impl fmt::Display for InvalidObjectKey {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid S3 object key: {}", self.reason)
+    }
+}
+
+impl fmt::Debug for InvalidObjectKey {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("InvalidObjectKey")
+            .field("reason", &self.reason)
+            .finish()
+    }
+}
@@ -0,0 +1,193 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::{object::ObjectInfo, paginate, xml, Etag, InvalidMetadata, Result, StorageClass};
+use async_std::io::{self, Read};
+use serde_derive::Deserialize;
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+/// A single page of an object listing.
+///
+/// A listing returns at most 1000 objects per page. When
+/// there are more objects, the page contains a continuation
+/// token that can be used to fetch the next page.
+#[derive(Debug, Clone)]
+pub struct ListObjects {
+    objects: Vec<ObjectInfo>,
+    prefixes: Vec<String>,
+    continuation_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Contents {
+    #[serde(rename(deserialize = "Key"))]
+    key: String,
+
+    #[serde(rename(deserialize = "LastModified"))]
+    last_modified: String,
+
+    #[serde(rename(deserialize = "ETag"))]
+    etag: String,
+
+    #[serde(rename(deserialize = "Size"))]
+    size: u64,
+
+    #[serde(rename(deserialize = "StorageClass"), default)]
+    storage_class: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CommonPrefix {
+    #[serde(rename(deserialize = "Prefix"))]
+    prefix: String,
+}
+
+impl ListObjects {
+    /// Returns the objects of this page.
+    #[inline]
+    pub fn objects(&self) -> &[ObjectInfo] {
+        &self.objects
+    }
+
+    /// Returns the common prefixes of this page. Common prefixes
+    /// are only returned when the listing uses a delimiter.
+    #[inline]
+    pub fn common_prefixes(&self) -> &[String] {
+        &self.prefixes
+    }
+
+    /// Returns the token for fetching the next page, or `None`
+    /// if this is the last page.
+    #[inline]
+    pub fn continuation_token(&self) -> Option<&str> {
+        self.continuation_token.as_deref()
+    }
+
+    /// Reports whether there are more pages.
+    #[inline]
+    pub fn is_truncated(&self) -> bool {
+        self.continuation_token.is_some()
+    }
+
+    /// Returns the objects of this page.
+    #[inline]
+    pub fn into_objects(self) -> Vec<ObjectInfo> {
+        self.objects
+    }
+}
+
+impl paginate::Page for ListObjects {
+    type Item = ObjectInfo;
+
+    #[inline]
+    fn next_token(&self) -> Option<&str> {
+        self.continuation_token()
+    }
+
+    #[inline]
+    fn into_items(self) -> Vec<ObjectInfo> {
+        self.into_objects()
+    }
+}
+
+/// An entry of an object listing.
+pub(crate) enum Entry {
+    Object(ObjectInfo),
+    Prefix(String),
+}
+
+/// An incremental reader for `ListObjectsV2` responses.
+///
+/// It parses the entries of a listing one by one while the
+/// response body is being received.
+pub(crate) struct Page<R> {
+    xml: xml::Reader<R>,
+}
+
+impl<R: Read + Unpin> Page<R> {
+    pub(crate) fn new(body: R) -> Self {
+        Self {
+            xml: xml::Reader::new(body, &["Contents", "CommonPrefixes"]),
+        }
+    }
+
+    /// Returns the next entry of the listing, or `None` once
+    /// all entries have been read.
+    pub(crate) async fn next(&mut self) -> Result<Option<Entry>> {
+        let (name, xml) = match self.xml.next().await {
+            Ok(Some(element)) => element,
+            Ok(None) => return Ok(None),
+            Err(err) if err.kind() == io::ErrorKind::InvalidData => {
+                return Err(InvalidMetadata::new().into())
+            }
+            Err(err) => return Err(surf::Error::from(err).into()),
+        };
+        match name.as_str() {
+            "Contents" => {
+                let contents: Contents =
+                    serde_xml_rs::from_str(&xml).map_err(|_| InvalidMetadata::new())?;
+                Ok(Some(Entry::Object(object(contents)?)))
+            }
+            _ => {
+                let prefix: CommonPrefix =
+                    serde_xml_rs::from_str(&xml).map_err(|_| InvalidMetadata::new())?;
+                Ok(Some(Entry::Prefix(prefix.prefix)))
+            }
+        }
+    }
+
+    /// Returns the token for fetching the next page, or `None`
+    /// if this is the last page.
+    ///
+    /// It is only valid once all entries have been read.
+    pub(crate) fn continuation_token(&self) -> Option<String> {
+        match self.xml.field("IsTruncated") {
+            Some("true") => self.xml.field("NextContinuationToken").map(String::from),
+            _ => None,
+        }
+    }
+
+    /// Reads all remaining entries of the listing.
+    pub(crate) async fn collect(mut self) -> Result<ListObjects> {
+        let mut objects = Vec::new();
+        let mut prefixes = Vec::new();
+        while let Some(entry) = self.next().await? {
+            match entry {
+                Entry::Object(object) => objects.push(object),
+                Entry::Prefix(prefix) => prefixes.push(prefix),
+            }
+        }
+        Ok(ListObjects {
+            objects,
+            prefixes,
+            continuation_token: self.continuation_token(),
+        })
+    }
+}
+
+fn object(contents: Contents) -> std::result::Result<ObjectInfo, InvalidMetadata> {
+    Ok(ObjectInfo::new(
+        contents.key,
+        contents.etag.parse::<Etag>()?,
+        contents.size,
+        OffsetDateTime::parse(&contents.last_modified, &Rfc3339)
+            .map_err(|_| InvalidMetadata::new())?,
+        match contents.storage_class {
+            // S3 omits the storage class of STANDARD objects.
+            Some(class) => class.parse().unwrap_or(StorageClass::Standard),
+            None => StorageClass::Standard,
+        },
+    ))
+}
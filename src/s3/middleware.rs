@@ -0,0 +1,97 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Hooks into the requests of a client.
+//!
+//! A [`Middleware`] observes or modifies each request of a
+//! [`Client`](crate::s3::Client) before it is signed, and each
+//! response after it has been received, e.g. for auditing,
+//! adding custom headers or injecting failures in tests.
+//!
+//! Requests pass the middleware in the order it has been added
+//! to the client, and responses in reverse order. A request that
+//! is retried or redirected passes the middleware again. Errors
+//! of the middleware fail the request. Presigned URLs and the
+//! requests of credentials providers do not pass the middleware.
+//!
+//! # Example
+//! ```
+//! use minio::s3::{middleware::{async_trait, Middleware}, Client, Credentials, Region, Result};
+//! use surf::http::{Method, Request, Response, Url};
+//!
+//! /// A middleware that tags requests and logs responses.
+//! #[derive(Debug)]
+//! struct Audit;
+//!
+//! #[async_trait]
+//! impl Middleware for Audit {
+//!     async fn on_request(&self, request: &mut Request) -> Result<()> {
+//!         request.insert_header("x-amz-meta-audit", "example");
+//!         Ok(())
+//!     }
+//!
+//!     async fn on_response(&self, method: Method, url: &Url, response: &mut Response) -> Result<()> {
+//!         println!("{} {} -> {}", method, url, response.status());
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let client = Client::new(Region::UsEast1, Credentials::anonym()).with_middleware(Audit);
+//! ```
+
+use crate::s3::Result;
+use std::{fmt, sync::Arc};
+use surf::http::{Method, Request, Response, Url};
+
+/// The attribute macro for implementing [`Middleware`].
+pub use http_client::async_trait;
+
+/// A hook into the requests and responses of a client.
+///
+/// Both methods do nothing by default.
+#[async_trait]
+pub trait Middleware: fmt::Debug + Send + Sync {
+    /// Observes or modifies a request before it is signed.
+    ///
+    /// The request is signed again after all middleware has
+    /// run, so added or changed headers are signed.
+    async fn on_request(&self, _request: &mut Request) -> Result<()> {
+        Ok(())
+    }
+
+    /// Observes or modifies the response to a request with
+    /// the given method and URL.
+    async fn on_response(
+        &self,
+        _method: Method,
+        _url: &Url,
+        _response: &mut Response,
+    ) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<T: Middleware + ?Sized> Middleware for Arc<T> {
+    #[inline]
+    async fn on_request(&self, request: &mut Request) -> Result<()> {
+        (**self).on_request(request).await
+    }
+
+    #[inline]
+    async fn on_response(&self, method: Method, url: &Url, response: &mut Response) -> Result<()> {
+        (**self).on_response(method, url, response).await
+    }
+}
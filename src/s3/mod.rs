@@ -17,10 +17,53 @@
 /// either some value of type `T` or an `s3::Error`.
 pub type Result<T> = std::result::Result<T, Error>;
 
+pub mod accelerate;
+
+pub mod access;
+
+pub use addressing::AddressingStyle;
+pub mod addressing;
+
+pub mod acl;
+
+#[cfg(feature = "aws-credential-types")]
+pub mod aws;
+
+pub mod blocking;
+
 pub use bucket::Bucket;
 pub mod bucket;
 
-pub use error::{Error, ErrorCode};
+pub use buckets::BucketInfo;
+pub mod buckets;
+
+pub use balancer::Balancer;
+pub mod balancer;
+
+pub use breaker::{CircuitBreaker, CircuitOpen, CircuitState};
+pub mod breaker;
+
+pub use cancel::CancellationToken;
+pub mod cancel;
+
+pub use checksum::{ChecksumAlgorithm, InvalidChecksumAlgorithm};
+pub mod checksum;
+
+pub use client::Client;
+mod client;
+
+pub use clock::Clock;
+pub mod clock;
+
+pub mod compose;
+
+pub use error::{Error, ErrorCode, ErrorKind};
+
+pub mod delete;
+
+pub mod download;
+
+pub mod convert;
 
 pub use credentials::Credentials;
 pub mod credentials;
@@ -28,12 +71,103 @@ pub mod credentials;
 pub use region::{InvalidRegion, Region};
 pub mod region;
 
-pub use etag::{Etag, InvalidEtag};
+pub use etag::{Etag, IntegrityError, InvalidEtag};
 pub mod etag;
 
-pub use object::{InvalidMetadata, Metadata, Object, StorageClass};
+pub use hedge::Hedging;
+pub mod hedge;
+
+pub mod find;
+
+pub mod inventory;
+
+pub use key::{InvalidObjectKey, ObjectKey};
+pub mod key;
+
+pub use list::ListObjects;
+pub mod list;
+
+pub mod lock;
+
+pub mod metrics;
+
+pub mod middleware;
+
+pub use multipart::MultipartUpload;
+pub mod multipart;
+
+pub use name::{BucketName, InvalidBucketName};
+pub mod name;
+
+pub mod notification;
+
+pub use object::{
+    ContentRange, DeleteObjectResult, GetObjectOptions, GetObjectOutcome, InvalidMetadata,
+    Metadata, Object, ObjectInfo, PutObjectOptions, PutObjectResult, RestoreStatus, RestoreTier,
+    StorageClass,
+};
 pub mod object;
 
+pub mod paginate;
+
+pub mod ownership;
+
+pub use payload::Payload;
+pub mod payload;
+
+pub mod pool;
+
+pub mod presign;
+
+pub use provider::CredentialsProvider;
+pub mod provider;
+
+pub mod proxy;
+
+pub mod request;
+
+pub use retry::RetryPolicy;
+pub mod retry;
+
+pub use sse::Sse;
+pub mod sse;
+
+pub mod sts;
+
+pub mod sv4;
+
+#[cfg(feature = "sigv4a")]
+pub mod sv4a;
+
+pub use tagging::TagSet;
+pub mod tagging;
+
+pub mod throttle;
+
+pub use timeout::Timeouts;
+pub mod timeout;
+
+pub mod tiering;
+
+pub mod tls;
+
+pub mod transfer;
+
+pub mod transport;
+
+pub use uri::{InvalidS3Uri, S3Uri};
+pub mod uri;
+
+pub use usage::Usage;
+pub mod usage;
+
+pub use wait::WaitPolicy;
+pub mod wait;
+
+pub mod watch;
+
+mod curl;
 mod error;
-mod request;
-mod sv4;
+#[cfg(feature = "tracing")]
+mod trace;
+mod xml;
@@ -0,0 +1,218 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::{convert::TryFrom, fmt, net::Ipv4Addr, ops::Deref, str::FromStr};
+
+/// A valid S3 bucket name.
+///
+/// A bucket name follows the S3 bucket naming rules:
+///  - it is between 3 and 63 characters long,
+///  - it consists of lowercase letters, digits, `.` and `-` only,
+///  - it begins and ends with a letter or digit,
+///  - it does not contain two adjacent periods,
+///  - it is not formatted as an IP address,
+///  - it does not use one of the prefixes or suffixes
+///    reserved by AWS, like `xn--` or `-s3alias`.
+///
+/// # Example
+/// ```
+/// use minio::s3::BucketName;
+///
+/// let name: BucketName = "my-bucket".parse().unwrap();
+/// assert_eq!("my-bucket", name.as_str());
+///
+/// assert!("My_Bucket".parse::<BucketName>().is_err());
+/// assert!("192.168.5.4".parse::<BucketName>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BucketName(String);
+
+/// A possible error when parsing an S3 bucket name.
+#[derive(Clone, PartialEq, Eq)]
+pub struct InvalidBucketName {
+    reason: &'static str,
+}
+
+const RESERVED_PREFIXES: [&str; 3] = ["xn--", "sthree-", "amzn-s3-demo-"];
+const RESERVED_SUFFIXES: [&str; 4] = ["-s3alias", "--ol-s3", ".mrap", "--x-s3"];
+
+impl BucketName {
+    /// Returns a new `BucketName` if the name follows the
+    /// S3 bucket naming rules.
+    pub fn new(name: impl AsRef<str>) -> Result<Self, InvalidBucketName> {
+        let name = name.as_ref();
+        if name.len() < 3 || name.len() > 63 {
+            return Err(InvalidBucketName::new(
+                "name must be between 3 and 63 characters long",
+            ));
+        }
+        if !name
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'.' || b == b'-')
+        {
+            return Err(InvalidBucketName::new(
+                "name must only contain lowercase letters, digits, '.' and '-'",
+            ));
+        }
+        let is_alphanumeric = |b: Option<&u8>| b.is_some_and(u8::is_ascii_alphanumeric);
+        if !is_alphanumeric(name.as_bytes().first()) || !is_alphanumeric(name.as_bytes().last()) {
+            return Err(InvalidBucketName::new(
+                "name must begin and end with a letter or digit",
+            ));
+        }
+        if name.contains("..") {
+            return Err(InvalidBucketName::new(
+                "name must not contain two adjacent periods",
+            ));
+        }
+        if name.parse::<Ipv4Addr>().is_ok() {
+            return Err(InvalidBucketName::new(
+                "name must not be formatted as an IP address",
+            ));
+        }
+        if RESERVED_PREFIXES.iter().any(|p| name.starts_with(p))
+            || RESERVED_SUFFIXES.iter().any(|s| name.ends_with(s))
+        {
+            return Err(InvalidBucketName::new(
+                "name uses a reserved prefix or suffix",
+            ));
+        }
+        Ok(Self(String::from(name)))
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    #[inline]
+    pub fn into_string(self) -> String {
+        self.0
+    }
+
+    /// Reports whether the bucket can be addressed as virtual
+    /// host, i.e. `<bucket>.<endpoint>`, when using TLS.
+    ///
+    /// Names containing periods are valid DNS names but do not
+    /// match the wildcard TLS certificate of the endpoint. Such
+    /// buckets must be addressed in path style.
+    ///
+    /// # Example
+    /// ```
+    /// use minio::s3::BucketName;
+    ///
+    /// let name: BucketName = "my-bucket".parse().unwrap();
+    /// assert!(name.is_virtual_host_compatible());
+    ///
+    /// let name: BucketName = "www.example.com".parse().unwrap();
+    /// assert!(!name.is_virtual_host_compatible());
+    /// ```
+    #[inline]
+    pub fn is_virtual_host_compatible(&self) -> bool {
+        !self.0.contains('.')
+    }
+}
+
+impl Deref for BucketName {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for BucketName {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for BucketName {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for BucketName {
+    type Err = InvalidBucketName;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for BucketName {
+    type Error = InvalidBucketName;
+
+    #[inline]
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        Self::new(s)
+    }
+}
+
+impl<'a> TryFrom<&'a String> for BucketName {
+    type Error = InvalidBucketName;
+
+    #[inline]
+    fn try_from(s: &'a String) -> Result<Self, Self::Error> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<String> for BucketName {
+    type Error = InvalidBucketName;
+
+    #[inline]
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::new(s)
+    }
+}
+
+impl From<BucketName> for String {
+    #[inline]
+    fn from(name: BucketName) -> Self {
+        name.0
+    }
+}
+
+// === InvalidBucketName ===
+
+impl InvalidBucketName {
+    fn new(reason: &'static str) -> Self {
+        Self { reason }
+    }
+}
+
+impl std::error::Error for InvalidBucketName {}
+
+impl fmt::Display for InvalidBucketName {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid S3 bucket name: {}", self.reason)
+    }
+}
+
+impl fmt::Debug for InvalidBucketName {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("InvalidBucketName")
+            .field("reason", &self.reason)
+            .finish()
+    }
+}
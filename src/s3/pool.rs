@@ -0,0 +1,190 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::{
+    fmt,
+    sync::{Arc, OnceLock},
+    time::Duration,
+};
+
+/// A pool of HTTP connections shared by S3 clients.
+///
+/// All clients, and all buckets created from them, share the
+/// connections of the process-wide [`ConnectionPool::shared`]
+/// pool by default. Connections to an endpoint are kept alive
+/// and reused across clients, buckets and requests.
+///
+/// A dedicated pool separates the connections of some clients
+/// from all others, for example to limit the number of connections
+/// a service opens to an endpoint. Clones of a pool share the same
+/// connections. The connections are established lazily, once the
+/// first request is sent.
+///
+/// # Example
+/// ```
+/// use minio::s3::{pool::ConnectionPool, Client, Credentials, Region};
+/// use std::time::Duration;
+///
+/// let pool = ConnectionPool::new()
+///     .with_max_connections_per_host(16)
+///     .with_idle_timeout(Duration::from_secs(30));
+///
+/// let client = Client::new(Region::UsEast1, Credentials::anonym()).with_connection_pool(pool);
+/// let logs = client.bucket("logs").unwrap();
+/// let backups = client.bucket("backups").unwrap();
+/// ```
+#[derive(Clone)]
+pub struct ConnectionPool {
+    max_connections: Option<usize>,
+    max_connections_per_host: Option<usize>,
+    max_idle_connections: Option<usize>,
+    idle_timeout: Option<Duration>,
+    client: Arc<OnceLock<isahc::HttpClient>>,
+}
+
+impl ConnectionPool {
+    /// Returns a new pool without any connection limits.
+    pub fn new() -> Self {
+        Self {
+            max_connections: None,
+            max_connections_per_host: None,
+            max_idle_connections: None,
+            idle_timeout: None,
+            client: Arc::default(),
+        }
+    }
+
+    /// Returns the process-wide pool that is used by all
+    /// clients without a dedicated pool.
+    ///
+    /// # Example
+    /// ```
+    /// use minio::s3::{pool::ConnectionPool, Client, Credentials, Region};
+    ///
+    /// let client = Client::new(Region::UsEast1, Credentials::anonym());
+    /// assert!(client.connection_pool().ptr_eq(&ConnectionPool::shared()));
+    /// ```
+    pub fn shared() -> Self {
+        static SHARED: OnceLock<ConnectionPool> = OnceLock::new();
+        SHARED.get_or_init(ConnectionPool::new).clone()
+    }
+
+    /// Sets the maximum number of connections the pool
+    /// opens at the same time.
+    ///
+    /// Requests exceeding the limit wait for a connection
+    /// to become available.
+    pub fn with_max_connections(mut self, max: usize) -> Self {
+        self.max_connections = Some(max);
+        self.client = Arc::default();
+        self
+    }
+
+    /// Sets the maximum number of connections the pool opens
+    /// to a single host at the same time.
+    pub fn with_max_connections_per_host(mut self, max: usize) -> Self {
+        self.max_connections_per_host = Some(max);
+        self.client = Arc::default();
+        self
+    }
+
+    /// Sets the maximum number of idle connections the pool
+    /// keeps alive for reuse.
+    pub fn with_max_idle_connections(mut self, max: usize) -> Self {
+        self.max_idle_connections = Some(max);
+        self.client = Arc::default();
+        self
+    }
+
+    /// Sets the time an idle connection is kept alive for
+    /// reuse before it is closed.
+    pub fn with_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_timeout = Some(timeout);
+        self.client = Arc::default();
+        self
+    }
+
+    #[inline]
+    pub fn max_connections(&self) -> Option<usize> {
+        self.max_connections
+    }
+
+    #[inline]
+    pub fn max_connections_per_host(&self) -> Option<usize> {
+        self.max_connections_per_host
+    }
+
+    #[inline]
+    pub fn max_idle_connections(&self) -> Option<usize> {
+        self.max_idle_connections
+    }
+
+    #[inline]
+    pub fn idle_timeout(&self) -> Option<Duration> {
+        self.idle_timeout
+    }
+
+    /// Reports whether both pools share the same connections,
+    /// i.e. one is a clone of the other.
+    #[inline]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.client, &other.client)
+    }
+
+    /// Returns the HTTP client owning the connections of
+    /// the pool.
+    ///
+    /// It fails if curl cannot be initialized, in which case
+    /// the next request tries again.
+    pub(crate) fn client(&self) -> Result<&isahc::HttpClient, isahc::Error> {
+        if let Some(client) = self.client.get() {
+            return Ok(client);
+        }
+        let mut builder = isahc::HttpClient::builder();
+        if let Some(max) = self.max_connections {
+            builder = builder.max_connections(max);
+        }
+        if let Some(max) = self.max_connections_per_host {
+            builder = builder.max_connections_per_host(max);
+        }
+        if let Some(max) = self.max_idle_connections {
+            builder = builder.connection_cache_size(max);
+        }
+        if let Some(timeout) = self.idle_timeout {
+            builder = builder.connection_cache_ttl(timeout);
+        }
+        // A client built concurrently by another request may win,
+        // in which case this one is dropped.
+        let client = builder.build()?;
+        Ok(self.client.get_or_init(|| client))
+    }
+}
+
+impl Default for ConnectionPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for ConnectionPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConnectionPool")
+            .field("max_connections", &self.max_connections)
+            .field("max_connections_per_host", &self.max_connections_per_host)
+            .field("max_idle_connections", &self.max_idle_connections)
+            .field("idle_timeout", &self.idle_timeout)
+            .finish()
+    }
+}
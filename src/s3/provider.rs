@@ -0,0 +1,1168 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Credentials providers.
+//!
+//! A [`Client`](crate::s3::Client) signs its requests with the
+//! credentials it has been created with. Alternatively, it
+//! resolves credentials lazily, right before sending a request,
+//! from a [`CredentialsProvider`]. Explicit credentials take
+//! precedence over the provider.
+//!
+//! The [`ProviderChain::default`] chain looks for credentials in:
+//!
+//!  1. The environment variables `AWS_ACCESS_KEY_ID`,
+//!     `AWS_SECRET_ACCESS_KEY` and `AWS_SESSION_TOKEN`.
+//!  2. The profile `AWS_PROFILE`, or `default`, of the shared
+//!     files `~/.aws/credentials` and `~/.aws/config`.
+//!  3. The web identity token file `AWS_WEB_IDENTITY_TOKEN_FILE`
+//!     of EKS pods with IAM roles for service accounts.
+//!  4. The container credentials endpoint of ECS tasks and of
+//!     EKS pods with EKS Pod Identity.
+//!  5. The instance metadata service (IMDSv2) of EC2 instances.
+//!
+//! # Example
+//! ```no_run
+//! use minio::s3::{provider::ProviderChain, Client, Credentials, Region};
+//! use async_std::task;
+//!
+//! let client = Client::new(Region::UsEast1, Credentials::anonym())
+//!     .with_credentials_provider(ProviderChain::default());
+//!
+//! let bucket = client.bucket("my-bucket").unwrap();
+//! task::block_on(bucket.exists()).unwrap();
+//! ```
+
+use crate::s3::{
+    credentials::TemporaryCredentials,
+    curl::CurlClient,
+    proxy::Proxy,
+    sts::{self, StsClient, WebIdentityProvider},
+    transport::Transport,
+    Credentials, Region, Result,
+};
+use async_std::{fs, future};
+use serde_derive::Deserialize;
+use std::{
+    collections::HashMap,
+    env,
+    error::Error as _,
+    fmt, io,
+    net::{Ipv4Addr, Ipv6Addr},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use surf::{
+    http::{headers, url::Host, Method, Request},
+    StatusCode, Url,
+};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+/// The attribute macro for implementing [`CredentialsProvider`].
+pub use http_client::async_trait;
+
+/// A source of credentials.
+///
+/// A provider is asked for credentials before each request of a
+/// client. Providers that fetch credentials remotely should cache
+/// them.
+#[async_trait]
+pub trait CredentialsProvider: fmt::Debug + Send + Sync {
+    /// Returns the credentials of the provider, or an error if
+    /// it has none.
+    async fn provide_credentials(&self) -> Result<Credentials>;
+}
+
+#[async_trait]
+impl<T: CredentialsProvider + ?Sized> CredentialsProvider for Arc<T> {
+    #[inline]
+    async fn provide_credentials(&self) -> Result<Credentials> {
+        (**self).provide_credentials().await
+    }
+}
+
+/// Explicit credentials provide themselves.
+#[async_trait]
+impl CredentialsProvider for Credentials {
+    async fn provide_credentials(&self) -> Result<Credentials> {
+        match self.is_anonym() {
+            true => Err(CredentialsError::new("no explicit credentials").into()),
+            false => Ok(self.clone()),
+        }
+    }
+}
+
+/// A possible error value when a provider has no credentials.
+pub struct CredentialsError {
+    reason: String,
+}
+
+/// A list of providers that are asked for credentials in order.
+///
+/// The chain returns the credentials of the first provider that
+/// has some. It fails if no provider has credentials.
+///
+/// # Example
+/// ```
+/// use minio::s3::provider::{Environment, Profile, ProviderChain};
+///
+/// let chain = ProviderChain::new()
+///     .with_provider(Environment::new())
+///     .with_provider(Profile::new().with_name("backup"));
+/// assert_eq!(2, chain.len());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProviderChain {
+    providers: Vec<Arc<dyn CredentialsProvider>>,
+}
+
+/// Provides credentials from the environment variables
+/// `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY` and
+/// `AWS_SESSION_TOKEN`.
+///
+/// The variables are read whenever credentials are requested.
+#[derive(Debug, Clone, Default)]
+pub struct Environment {
+    _priv: (),
+}
+
+/// Provides credentials from a profile of the shared AWS
+/// configuration files.
+///
+/// The credentials file is `AWS_SHARED_CREDENTIALS_FILE`, if
+/// set, or else `.aws/credentials` in the home directory. The
+/// config file is `AWS_CONFIG_FILE`, if set, or else `.aws/config`
+/// in the home directory. The profile is `AWS_PROFILE`, if set,
+/// or else `default`.
+///
+/// Both files are INI files. A profile is a section named after
+/// the profile in the credentials file, and a section named
+/// `profile <name>`, or just `default`, in the config file. Keys
+/// of the credentials file take precedence. A profile consists of
+/// the keys `aws_access_key_id`, `aws_secret_access_key` and,
+/// optionally, `aws_session_token`. A profile without keys may
+/// refer to another profile by `source_profile`.
+///
+/// Instead of keys, a profile may name a `credential_process`,
+/// a command that prints credentials as JSON to its standard
+/// output. The command is run by the shell, `sh` or `cmd`.
+/// Credentials with an `Expiration` are cached until shortly
+/// before they expire.
+///
+/// A profile with a `role_arn` assumes the role with the
+/// credentials of its `source_profile`, via the STS endpoint of
+/// the profile's `region`. The keys `role_session_name`,
+/// `external_id` and `duration_seconds` configure the session.
+/// Assumed credentials are cached until shortly before they
+/// expire.
+///
+/// # Example
+/// ```no_run
+/// use minio::s3::provider::{CredentialsProvider, Profile};
+/// use async_std::task;
+///
+/// let profile = Profile::new()
+///     .with_credentials_file("/etc/minio/credentials")
+///     .with_name("backup");
+/// let credentials = task::block_on(profile.provide_credentials()).unwrap();
+/// let region = task::block_on(profile.load_region()).unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Profile {
+    credentials_file: Option<PathBuf>,
+    config_file: Option<PathBuf>,
+    name: Option<String>,
+    transport: Option<Arc<dyn Transport>>,
+    cache: Cache,
+}
+
+/// Provides the credentials of the IAM role of an EC2 instance
+/// from the instance metadata service (IMDSv2).
+///
+/// The credentials are cached until shortly before they expire.
+/// The service is reached at `AWS_EC2_METADATA_SERVICE_ENDPOINT`,
+/// if set, or else at `http://169.254.169.254`. Setting
+/// `AWS_EC2_METADATA_DISABLED` to `true` disables the provider.
+#[derive(Clone)]
+pub struct InstanceMetadata {
+    endpoint: Option<Url>,
+    timeout: Duration,
+    transport: Arc<dyn Transport>,
+    cache: Cache,
+}
+
+/// Provides the credentials of an ECS task or of an EKS pod from
+/// the container credentials endpoint.
+///
+/// The endpoint is `http://169.254.170.2` followed by
+/// `AWS_CONTAINER_CREDENTIALS_RELATIVE_URI`, as set by ECS, or
+/// else `AWS_CONTAINER_CREDENTIALS_FULL_URI`, as set by EKS Pod
+/// Identity. A full URI must be an `https` URI or refer to a
+/// loopback address or to one of the container endpoints of ECS
+/// and EKS.
+///
+/// Requests are authorized by the content of the file
+/// `AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE`, if set, or else by
+/// `AWS_CONTAINER_AUTHORIZATION_TOKEN`. The file is read again
+/// for each request, since the token is rotated. The credentials
+/// are cached until shortly before they expire.
+///
+/// # Example
+/// ```no_run
+/// use minio::s3::provider::{Container, CredentialsProvider};
+/// use async_std::task;
+///
+/// let provider = Container::new()
+///     .with_endpoint("http://169.254.170.23/v1/credentials".parse().unwrap())
+///     .with_authorization_token_file(
+///         "/var/run/secrets/pods.eks.amazonaws.com/serviceaccount/eks-pod-identity-token",
+///     );
+/// let credentials = task::block_on(provider.provide_credentials()).unwrap();
+/// ```
+#[derive(Clone)]
+pub struct Container {
+    endpoint: Option<Url>,
+    authorization: Option<Authorization>,
+    timeout: Duration,
+    transport: Arc<dyn Transport>,
+    cache: Cache,
+}
+
+/// The authorization of requests to the container
+/// credentials endpoint.
+#[derive(Clone, PartialEq, Eq)]
+enum Authorization {
+    Token(String),
+    File(PathBuf),
+}
+
+/// Temporary credentials cached by a provider until
+/// shortly before they expire.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Cache {
+    credentials: Arc<Mutex<Option<TemporaryCredentials>>>,
+}
+
+impl CredentialsError {
+    pub(crate) fn new(reason: impl Into<String>) -> Self {
+        Self {
+            reason: reason.into(),
+        }
+    }
+}
+
+impl std::error::Error for CredentialsError {}
+
+impl fmt::Display for CredentialsError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "credentials unavailable: {}", self.reason)
+    }
+}
+
+impl fmt::Debug for CredentialsError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CredentialsError")
+            .field("reason", &self.reason)
+            .finish()
+    }
+}
+
+impl ProviderChain {
+    /// Returns an empty chain.
+    pub fn new() -> Self {
+        Self {
+            providers: Vec::new(),
+        }
+    }
+
+    /// Appends the provider to the chain.
+    pub fn with_provider(mut self, provider: impl CredentialsProvider + 'static) -> Self {
+        self.providers.push(Arc::new(provider));
+        self
+    }
+
+    /// Returns the number of providers in the chain.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.providers.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.providers.is_empty()
+    }
+}
+
+/// Returns the default chain of the [module documentation](self).
+impl Default for ProviderChain {
+    fn default() -> Self {
+        Self::new()
+            .with_provider(Environment::new())
+            .with_provider(Profile::new())
+            .with_provider(WebIdentityProvider::new())
+            .with_provider(Container::new())
+            .with_provider(InstanceMetadata::new())
+    }
+}
+
+#[async_trait]
+impl CredentialsProvider for ProviderChain {
+    async fn provide_credentials(&self) -> Result<Credentials> {
+        let mut reasons = Vec::with_capacity(self.providers.len());
+        for provider in &self.providers {
+            match provider.provide_credentials().await {
+                Ok(credentials) => return Ok(credentials),
+                // The reason of a credentials error is its source.
+                Err(err) => reasons.push(match err.source() {
+                    Some(source) => source.to_string(),
+                    None => err.to_string(),
+                }),
+            }
+        }
+        match reasons.is_empty() {
+            true => Err(CredentialsError::new("empty provider chain").into()),
+            false => Err(CredentialsError::new(reasons.join("; ")).into()),
+        }
+    }
+}
+
+impl Environment {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl CredentialsProvider for Environment {
+    async fn provide_credentials(&self) -> Result<Credentials> {
+        let var = |name: &str| env::var(name).ok().filter(|value| !value.is_empty());
+        let (access_key, secret_key) =
+            match (var("AWS_ACCESS_KEY_ID"), var("AWS_SECRET_ACCESS_KEY")) {
+                (Some(access_key), Some(secret_key)) => (access_key, secret_key),
+                _ => {
+                    return Err(CredentialsError::new(
+                        "AWS_ACCESS_KEY_ID or AWS_SECRET_ACCESS_KEY not set",
+                    )
+                    .into())
+                }
+            };
+        let mut credentials = Credentials::new()
+            .access_key(access_key)
+            .secret_key(secret_key);
+        if let Some(session_token) = var("AWS_SESSION_TOKEN") {
+            credentials = credentials.session_token(session_token);
+        }
+        Ok(credentials.into())
+    }
+}
+
+impl Profile {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the shared credentials file, replacing
+    /// `AWS_SHARED_CREDENTIALS_FILE`.
+    pub fn with_credentials_file(mut self, file: impl Into<PathBuf>) -> Self {
+        self.credentials_file = Some(file.into());
+        self.cache = Cache::default();
+        self
+    }
+
+    /// Sets the shared config file, replacing `AWS_CONFIG_FILE`.
+    pub fn with_config_file(mut self, file: impl Into<PathBuf>) -> Self {
+        self.config_file = Some(file.into());
+        self.cache = Cache::default();
+        self
+    }
+
+    /// Sets the name of the profile, replacing `AWS_PROFILE`.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self.cache = Cache::default();
+        self
+    }
+
+    /// Sets the transport requests to STS are sent with, when
+    /// the profile assumes a role.
+    pub fn with_transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self.cache = Cache::default();
+        self
+    }
+
+    /// Returns the shared credentials file, if any.
+    pub fn credentials_file(&self) -> Option<PathBuf> {
+        match self.credentials_file {
+            Some(ref file) => Some(file.clone()),
+            None => match env::var_os("AWS_SHARED_CREDENTIALS_FILE") {
+                Some(file) => Some(PathBuf::from(file)),
+                None => home_dir().map(|home| home.join(".aws").join("credentials")),
+            },
+        }
+    }
+
+    /// Returns the shared config file, if any.
+    pub fn config_file(&self) -> Option<PathBuf> {
+        match self.config_file {
+            Some(ref file) => Some(file.clone()),
+            None => match env::var_os("AWS_CONFIG_FILE") {
+                Some(file) => Some(PathBuf::from(file)),
+                None => home_dir().map(|home| home.join(".aws").join("config")),
+            },
+        }
+    }
+
+    /// Returns the name of the profile.
+    pub fn name(&self) -> String {
+        match self.name {
+            Some(ref name) => name.clone(),
+            None => env::var("AWS_PROFILE").unwrap_or_else(|_| String::from("default")),
+        }
+    }
+
+    /// Returns the region of the profile, as configured by the
+    /// key `region`, if any.
+    pub async fn load_region(&self) -> Result<Option<Region>> {
+        let profiles = self.load().await?;
+        let name = self.name();
+        match profiles.get(&name, "region") {
+            Some(region) => match region.parse() {
+                Ok(region) => Ok(Some(region)),
+                Err(_) => Err(CredentialsError::new(format!(
+                    "profile {} has invalid region {}",
+                    name, region
+                ))
+                .into()),
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Loads the profiles of both files. Missing files
+    /// have no profiles.
+    async fn load(&self) -> Result<Profiles> {
+        let mut profiles = Profiles::default();
+        if let Some(file) = self.config_file() {
+            if let Some(content) = read_optional(&file).await? {
+                for (section, properties) in ini::parse(&content) {
+                    let name = match section.strip_prefix("profile ") {
+                        Some(name) => name.trim(),
+                        None if section == "default" => "default",
+                        // Other sections, like `sso-session`, are
+                        // no profiles.
+                        None => continue,
+                    };
+                    profiles.insert(name, properties);
+                }
+            }
+        }
+        // Keys of the credentials file take precedence.
+        if let Some(file) = self.credentials_file() {
+            if let Some(content) = read_optional(&file).await? {
+                for (section, properties) in ini::parse(&content) {
+                    profiles.insert(&section, properties);
+                }
+            }
+        }
+        Ok(profiles)
+    }
+}
+
+#[async_trait]
+impl CredentialsProvider for Profile {
+    async fn provide_credentials(&self) -> Result<Credentials> {
+        if let Some(credentials) = self.cache.get() {
+            return Ok(credentials);
+        }
+        let profiles = self.load().await?;
+        let mut name = self.name();
+        let mut visited = Vec::new();
+        // The profiles that assume a role, each with the
+        // credentials of the next one.
+        let mut roles = Vec::new();
+        let mut credentials = loop {
+            if visited.contains(&name) {
+                return Err(CredentialsError::new(format!(
+                    "profile {} refers to itself via source_profile",
+                    name
+                ))
+                .into());
+            }
+            let profile = profiles
+                .profile(&name)
+                .ok_or_else(|| CredentialsError::new(format!("no profile {}", name)))?;
+            let source = profile.get("source_profile").cloned();
+            match (profile.contains_key("role_arn"), source) {
+                // A profile may assume a role with its own keys.
+                (true, Some(source)) if source == name => {
+                    roles.push(profile);
+                    break static_credentials(&name, profile)?;
+                }
+                (true, Some(source)) => {
+                    roles.push(profile);
+                    visited.push(std::mem::replace(&mut name, source));
+                }
+                (true, None) => {
+                    return Err(CredentialsError::new(format!(
+                        "profile {} has a role_arn but no source_profile",
+                        name
+                    ))
+                    .into())
+                }
+                (false, Some(source))
+                    if !profile.contains_key("aws_access_key_id")
+                        && !profile.contains_key("credential_process") =>
+                {
+                    visited.push(std::mem::replace(&mut name, source));
+                }
+                (false, _) if profile.contains_key("aws_access_key_id") => {
+                    break static_credentials(&name, profile)?
+                }
+                (false, _) => match profile.get("credential_process") {
+                    Some(command) => break process::credentials(command).await?,
+                    None => break static_credentials(&name, profile)?,
+                },
+            }
+        };
+        if roles.is_empty() {
+            return match credentials.expiration() {
+                Some(expiration) => Ok(self
+                    .cache
+                    .set(TemporaryCredentials::new(credentials, expiration))),
+                None => Ok(credentials),
+            };
+        }
+
+        let mut temporary = None;
+        for profile in roles.into_iter().rev() {
+            let region = match profile.get("region").map(|region| region.parse()) {
+                Some(Ok(region)) => region,
+                _ => Region::UsEast1,
+            };
+            let mut sts = StsClient::new(region);
+            if let Some(ref transport) = self.transport {
+                sts = sts.with_transport(transport.clone());
+            }
+            let session_name = match profile.get("role_session_name") {
+                Some(session_name) => session_name.clone(),
+                None => sts::session_name(),
+            };
+            let mut options = Vec::new();
+            if let Some(external_id) = profile.get("external_id") {
+                options.push(("ExternalId", external_id.as_str()));
+            }
+            if let Some(duration) = profile.get("duration_seconds") {
+                options.push(("DurationSeconds", duration.as_str()));
+            }
+            let role = sts
+                .assume_role(&credentials, &profile["role_arn"], &session_name, &options)
+                .await?;
+            credentials = role.credentials().clone();
+            temporary = Some(role);
+        }
+        Ok(self.cache.set(temporary.expect("at least one role")))
+    }
+}
+
+/// Returns the static credentials of the profile.
+fn static_credentials(name: &str, profile: &HashMap<String, String>) -> Result<Credentials> {
+    let missing = |key: &str| CredentialsError::new(format!("profile {} has no {}", name, key));
+    let access_key = profile
+        .get("aws_access_key_id")
+        .ok_or_else(|| missing("aws_access_key_id"))?;
+    let secret_key = profile
+        .get("aws_secret_access_key")
+        .ok_or_else(|| missing("aws_secret_access_key"))?;
+    let mut credentials = Credentials::new()
+        .access_key(access_key)
+        .secret_key(secret_key);
+    if let Some(session_token) = profile.get("aws_session_token") {
+        credentials = credentials.session_token(session_token);
+    }
+    Ok(credentials.into())
+}
+
+/// Runs the `credential_process` of a profile.
+mod process {
+    use super::CredentialsError;
+    use crate::s3::{Credentials, Result};
+    use async_std::task;
+    use serde_derive::Deserialize;
+    use std::process::Command;
+    use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+    /// The credentials printed by a process.
+    #[derive(Debug, Deserialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct ProcessCredentials {
+        version: u32,
+        access_key_id: String,
+        secret_access_key: String,
+        #[serde(default)]
+        session_token: Option<String>,
+        #[serde(default)]
+        expiration: Option<String>,
+    }
+
+    /// Runs the command and returns the credentials it prints.
+    pub(super) async fn credentials(command: &str) -> Result<Credentials> {
+        let mut process = if cfg!(windows) {
+            let mut process = Command::new("cmd");
+            process.args(["/C", command]);
+            process
+        } else {
+            let mut process = Command::new("sh");
+            process.args(["-c", command]);
+            process
+        };
+        let output = task::spawn_blocking(move || process.output())
+            .await
+            .map_err(|err| {
+                CredentialsError::new(format!("cannot run credential_process: {}", err))
+            })?;
+        if !output.status.success() {
+            return Err(CredentialsError::new(format!(
+                "credential_process failed with {}: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ))
+            .into());
+        }
+        parse(&output.stdout)
+    }
+
+    /// Parses the JSON output of a process.
+    fn parse(output: &[u8]) -> Result<Credentials> {
+        let invalid = |err: &dyn std::fmt::Display| {
+            CredentialsError::new(format!("invalid credential_process output: {}", err))
+        };
+        let process: ProcessCredentials =
+            serde_json::from_slice(output).map_err(|err| invalid(&err))?;
+        if process.version != 1 {
+            return Err(invalid(&format!("unsupported version {}", process.version)).into());
+        }
+        let mut credentials = Credentials::new()
+            .access_key(process.access_key_id)
+            .secret_key(process.secret_access_key);
+        if let Some(session_token) = process.session_token {
+            credentials = credentials.session_token(session_token);
+        }
+        if let Some(expiration) = process.expiration {
+            let expiration =
+                OffsetDateTime::parse(&expiration, &Rfc3339).map_err(|err| invalid(&err))?;
+            credentials = credentials.expiration(expiration);
+        }
+        Ok(credentials.into())
+    }
+}
+
+/// The profiles of the shared AWS configuration files.
+#[derive(Debug, Default)]
+struct Profiles {
+    profiles: HashMap<String, HashMap<String, String>>,
+}
+
+impl Profiles {
+    /// Adds the properties to the profile, replacing
+    /// existing keys.
+    fn insert(&mut self, name: &str, properties: HashMap<String, String>) {
+        self.profiles
+            .entry(String::from(name))
+            .or_default()
+            .extend(properties);
+    }
+
+    fn profile(&self, name: &str) -> Option<&HashMap<String, String>> {
+        self.profiles.get(name)
+    }
+
+    fn get(&self, name: &str, key: &str) -> Option<&String> {
+        self.profile(name).and_then(|profile| profile.get(key))
+    }
+}
+
+/// Reads the file, which may not exist.
+async fn read_optional(file: &Path) -> Result<Option<String>> {
+    match fs::read_to_string(file).await {
+        Ok(content) => Ok(Some(content)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => {
+            Err(CredentialsError::new(format!("cannot read {}: {}", file.display(), err)).into())
+        }
+    }
+}
+
+/// A parser for the INI format of the shared AWS
+/// configuration files.
+mod ini {
+    use std::collections::HashMap;
+
+    /// Returns the sections of the INI document with their
+    /// properties. Sections that occur more than once are
+    /// merged.
+    ///
+    /// Comments start with `#` or `;`, at the beginning of a
+    /// line or after whitespace. Keys and values are trimmed.
+    /// Indented lines below a key without value are nested
+    /// properties and skipped.
+    pub(super) fn parse(content: &str) -> HashMap<String, HashMap<String, String>> {
+        let mut sections: HashMap<String, HashMap<String, String>> = HashMap::new();
+        let mut section = None;
+        for line in content.lines() {
+            let nested = line.starts_with(|c: char| c.is_whitespace());
+            let line = strip_comment(line).trim();
+            if line.is_empty() || nested {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                let name = name.split_whitespace().collect::<Vec<_>>().join(" ");
+                sections.entry(name.clone()).or_default();
+                section = Some(name);
+                continue;
+            }
+            if let (Some(name), Some((key, value))) = (section.as_ref(), line.split_once('=')) {
+                let properties = sections.get_mut(name).expect("section exists");
+                properties.insert(key.trim().to_owned(), value.trim().to_owned());
+            }
+        }
+        sections
+    }
+
+    fn strip_comment(line: &str) -> &str {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with('#') || trimmed.starts_with(';') {
+            return "";
+        }
+        let comment = line
+            .char_indices()
+            .zip(line.chars().skip(1))
+            .find(|((_, c), next)| c.is_whitespace() && (*next == '#' || *next == ';'));
+        match comment {
+            Some(((i, _), _)) => &line[..i],
+            None => line,
+        }
+    }
+}
+
+/// Returns the home directory of the current user.
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .filter(|home| !home.is_empty())
+        .map(PathBuf::from)
+}
+
+/// The security credentials of an IAM role, as returned by
+/// the instance metadata service and by the container
+/// credentials endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RoleCredentials {
+    #[serde(default)]
+    code: Option<String>,
+    access_key_id: String,
+    secret_access_key: String,
+    token: String,
+    expiration: String,
+}
+
+impl RoleCredentials {
+    /// Parses the credentials from the JSON response of
+    /// the service.
+    fn parse(service: &str, body: &str) -> Result<TemporaryCredentials> {
+        let role: Self = serde_json::from_str(body).map_err(|err| {
+            CredentialsError::new(format!("invalid {} credentials: {}", service, err))
+        })?;
+        match role.code.as_deref() {
+            Some("Success") | None => (),
+            Some(code) => {
+                return Err(
+                    CredentialsError::new(format!("{} responded with {}", service, code)).into(),
+                )
+            }
+        }
+        let expiration = OffsetDateTime::parse(&role.expiration, &Rfc3339).map_err(|err| {
+            CredentialsError::new(format!("invalid {} expiration: {}", service, err))
+        })?;
+        let credentials = Credentials::new()
+            .access_key(role.access_key_id)
+            .secret_key(role.secret_access_key)
+            .session_token(role.token);
+        Ok(TemporaryCredentials::new(credentials.into(), expiration))
+    }
+}
+
+impl Cache {
+    /// Credentials are fetched again once they expire
+    /// within this duration.
+    const EXPIRY_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+    /// Returns the cached credentials unless they are
+    /// about to expire.
+    pub(crate) fn get(&self) -> Option<Credentials> {
+        let cached = self.credentials.lock().expect("credentials cache poisoned");
+        match *cached {
+            Some(ref credentials)
+                if !credentials
+                    .credentials()
+                    .expires_within(Self::EXPIRY_MARGIN) =>
+            {
+                Some(credentials.credentials().clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// Caches the credentials and returns them.
+    pub(crate) fn set(&self, credentials: TemporaryCredentials) -> Credentials {
+        *self.credentials.lock().expect("credentials cache poisoned") = Some(credentials.clone());
+        credentials.into_credentials()
+    }
+}
+
+/// Sends a request to a credentials service and returns the
+/// body of the response.
+async fn fetch(
+    service: &str,
+    transport: &dyn Transport,
+    timeout: Duration,
+    request: Request,
+) -> Result<String> {
+    let path = request.url().path().to_owned();
+    let send = transport.send(request);
+    let mut response = match future::timeout(timeout, send).await {
+        Ok(response) => response?,
+        Err(_) => {
+            return Err(
+                CredentialsError::new(format!("{} did not respond to {}", service, path)).into(),
+            )
+        }
+    };
+    match response.status() {
+        StatusCode::Ok => Ok(response.body_string().await?),
+        status => Err(CredentialsError::new(format!(
+            "{} responded to {} with {}",
+            service, path, status
+        ))
+        .into()),
+    }
+}
+
+impl InstanceMetadata {
+    /// The default endpoint of the instance metadata service.
+    pub const ENDPOINT: &'static str = "http://169.254.169.254";
+
+    const SERVICE: &'static str = "instance metadata service";
+
+    /// The lifetime of a session token of the service.
+    const TOKEN_TTL: &'static str = "21600";
+
+    pub fn new() -> Self {
+        Self {
+            endpoint: None,
+            timeout: Duration::from_secs(1),
+            transport: Arc::new(CurlClient::new().with_proxy(Proxy::none())),
+            cache: Cache::default(),
+        }
+    }
+
+    /// Sets the endpoint of the instance metadata service,
+    /// replacing `AWS_EC2_METADATA_SERVICE_ENDPOINT`.
+    pub fn with_endpoint(mut self, endpoint: Url) -> Self {
+        self.endpoint = Some(endpoint);
+        self
+    }
+
+    /// Sets the time the provider waits for each response of
+    /// the service. It defaults to one second, such that the
+    /// provider fails fast outside of EC2.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the transport requests to the service are sent with.
+    pub fn with_transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Arc::new(transport);
+        self
+    }
+
+    /// Returns the endpoint of the instance metadata service.
+    pub fn endpoint(&self) -> Result<Url> {
+        match self.endpoint {
+            Some(ref endpoint) => Ok(endpoint.clone()),
+            None => match env::var("AWS_EC2_METADATA_SERVICE_ENDPOINT") {
+                Ok(endpoint) => Ok(endpoint.parse()?),
+                Err(_) => Ok(Self::ENDPOINT.parse()?),
+            },
+        }
+    }
+
+    #[inline]
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    async fn fetch(&self, request: Request) -> Result<String> {
+        fetch(
+            Self::SERVICE,
+            self.transport.as_ref(),
+            self.timeout,
+            request,
+        )
+        .await
+    }
+
+    /// Fetches the credentials of the instance's IAM role.
+    async fn fetch_credentials(&self) -> Result<TemporaryCredentials> {
+        let endpoint = self.endpoint()?;
+
+        let mut request = Request::new(Method::Put, endpoint.join("/latest/api/token")?);
+        request.insert_header("X-aws-ec2-metadata-token-ttl-seconds", Self::TOKEN_TTL);
+        let token = self.fetch(request).await?;
+
+        let url = endpoint.join("/latest/meta-data/iam/security-credentials/")?;
+        let mut request = Request::new(Method::Get, url.clone());
+        request.insert_header("X-aws-ec2-metadata-token", token.as_str());
+        let roles = self.fetch(request).await?;
+        let role = roles
+            .lines()
+            .map(str::trim)
+            .find(|role| !role.is_empty())
+            .ok_or_else(|| CredentialsError::new("instance has no IAM role"))?;
+
+        let mut request = Request::new(Method::Get, url.join(role)?);
+        request.insert_header("X-aws-ec2-metadata-token", token.as_str());
+        let body = self.fetch(request).await?;
+        RoleCredentials::parse(Self::SERVICE, &body)
+    }
+}
+
+impl Default for InstanceMetadata {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for InstanceMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("InstanceMetadata")
+            .field("endpoint", &self.endpoint)
+            .field("timeout", &self.timeout)
+            .field("transport", &self.transport)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl CredentialsProvider for InstanceMetadata {
+    async fn provide_credentials(&self) -> Result<Credentials> {
+        if env::var("AWS_EC2_METADATA_DISABLED").is_ok_and(|v| v.eq_ignore_ascii_case("true")) {
+            return Err(CredentialsError::new("instance metadata service disabled").into());
+        }
+        match self.cache.get() {
+            Some(credentials) => Ok(credentials),
+            None => Ok(self.cache.set(self.fetch_credentials().await?)),
+        }
+    }
+}
+
+impl Container {
+    /// The endpoint relative URIs refer to.
+    pub const ENDPOINT: &'static str = "http://169.254.170.2";
+
+    const SERVICE: &'static str = "container credentials endpoint";
+
+    pub fn new() -> Self {
+        Self {
+            endpoint: None,
+            authorization: None,
+            timeout: Duration::from_secs(2),
+            transport: Arc::new(CurlClient::new().with_proxy(Proxy::none())),
+            cache: Cache::default(),
+        }
+    }
+
+    /// Sets the full URI of the container credentials endpoint,
+    /// replacing the environment variables.
+    pub fn with_endpoint(mut self, endpoint: Url) -> Self {
+        self.endpoint = Some(endpoint);
+        self
+    }
+
+    /// Sets the token requests are authorized with, replacing
+    /// the environment variables.
+    pub fn with_authorization_token(mut self, token: impl Into<String>) -> Self {
+        self.authorization = Some(Authorization::Token(token.into()));
+        self
+    }
+
+    /// Sets the file containing the token requests are authorized
+    /// with, replacing the environment variables.
+    pub fn with_authorization_token_file(mut self, file: impl Into<PathBuf>) -> Self {
+        self.authorization = Some(Authorization::File(file.into()));
+        self
+    }
+
+    /// Sets the time the provider waits for a response of the
+    /// endpoint. It defaults to two seconds.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the transport requests to the endpoint are sent with.
+    pub fn with_transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Arc::new(transport);
+        self
+    }
+
+    /// Returns the URI of the container credentials endpoint.
+    ///
+    /// It fails if no endpoint is configured or if a full URI
+    /// refers to a host that is not allowed.
+    pub fn endpoint(&self) -> Result<Url> {
+        let endpoint = match self.endpoint {
+            Some(ref endpoint) => endpoint.clone(),
+            None => match env::var("AWS_CONTAINER_CREDENTIALS_RELATIVE_URI") {
+                Ok(uri) => Url::parse(Self::ENDPOINT)?.join(&uri)?,
+                Err(_) => match env::var("AWS_CONTAINER_CREDENTIALS_FULL_URI") {
+                    Ok(uri) => uri.parse()?,
+                    Err(_) => {
+                        return Err(CredentialsError::new(
+                            "AWS_CONTAINER_CREDENTIALS_RELATIVE_URI or \
+                             AWS_CONTAINER_CREDENTIALS_FULL_URI not set",
+                        )
+                        .into())
+                    }
+                },
+            },
+        };
+        match Self::is_allowed(&endpoint) {
+            true => Ok(endpoint),
+            false => Err(CredentialsError::new(format!(
+                "container credentials endpoint {} is neither https nor local",
+                endpoint
+            ))
+            .into()),
+        }
+    }
+
+    #[inline]
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Reports whether credentials may be fetched from the URI:
+    /// `https` URIs and URIs of loopback addresses or of the
+    /// container endpoints of ECS and EKS.
+    fn is_allowed(endpoint: &Url) -> bool {
+        if endpoint.scheme() == "https" {
+            return true;
+        }
+        match endpoint.host() {
+            Some(Host::Ipv4(ip)) => {
+                ip.is_loopback()
+                    || ip == Ipv4Addr::new(169, 254, 170, 2)
+                    || ip == Ipv4Addr::new(169, 254, 170, 23)
+            }
+            Some(Host::Ipv6(ip)) => {
+                ip.is_loopback() || ip == Ipv6Addr::new(0xfd00, 0xec2, 0, 0, 0, 0, 0, 0x23)
+            }
+            Some(Host::Domain(domain)) => domain == "localhost",
+            None => false,
+        }
+    }
+
+    /// Returns the token requests are authorized with, if any.
+    async fn authorization_token(&self) -> Result<Option<String>> {
+        let authorization = match self.authorization {
+            Some(ref authorization) => Some(authorization.clone()),
+            None => match env::var_os("AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE") {
+                Some(file) => Some(Authorization::File(PathBuf::from(file))),
+                None => env::var("AWS_CONTAINER_AUTHORIZATION_TOKEN")
+                    .ok()
+                    .map(Authorization::Token),
+            },
+        };
+        match authorization {
+            Some(Authorization::Token(token)) => Ok(Some(token)),
+            Some(Authorization::File(file)) => match fs::read_to_string(&file).await {
+                Ok(token) => Ok(Some(token.trim().to_owned())),
+                Err(err) => {
+                    Err(
+                        CredentialsError::new(format!("cannot read {}: {}", file.display(), err))
+                            .into(),
+                    )
+                }
+            },
+            None => Ok(None),
+        }
+    }
+
+    async fn fetch_credentials(&self) -> Result<TemporaryCredentials> {
+        let mut request = Request::new(Method::Get, self.endpoint()?);
+        if let Some(token) = self.authorization_token().await? {
+            if token.contains(['\r', '\n']) {
+                return Err(CredentialsError::new("invalid container authorization token").into());
+            }
+            request.insert_header(headers::AUTHORIZATION, token.as_str());
+        }
+        let body = fetch(
+            Self::SERVICE,
+            self.transport.as_ref(),
+            self.timeout,
+            request,
+        )
+        .await?;
+        RoleCredentials::parse(Self::SERVICE, &body)
+    }
+}
+
+impl Default for Container {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Container {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let authorization = match self.authorization {
+            Some(Authorization::Token(_)) => Some("<redacted>"),
+            Some(Authorization::File(_)) => Some("<file>"),
+            None => None,
+        };
+        f.debug_struct("Container")
+            .field("endpoint", &self.endpoint)
+            .field("authorization", &authorization)
+            .field("timeout", &self.timeout)
+            .field("transport", &self.transport)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl CredentialsProvider for Container {
+    async fn provide_credentials(&self) -> Result<Credentials> {
+        match self.cache.get() {
+            Some(credentials) => Ok(credentials),
+            None => Ok(self.cache.set(self.fetch_credentials().await?)),
+        }
+    }
+}
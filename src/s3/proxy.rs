@@ -0,0 +1,261 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Outbound proxies for S3 requests.
+//!
+//! By default, requests honor the proxy environment variables:
+//!
+//!  - `HTTPS_PROXY` for `https` endpoints and `HTTP_PROXY` for
+//!    `http` endpoints, falling back to `ALL_PROXY`.
+//!  - `NO_PROXY` as comma-separated list of hosts and domains
+//!    that are reached directly. A `*` disables the proxy for
+//!    all hosts.
+//!
+//! The lowercase variants of the variables take precedence. An
+//! explicitly configured [`Proxy`] replaces the environment.
+//! Proxies only apply to the default transport.
+//!
+//! # Example
+//! ```
+//! use minio::s3::{proxy::Proxy, Client, Credentials, Region};
+//!
+//! let proxy = Proxy::new("http://proxy.example.com:3128")
+//!     .unwrap()
+//!     .with_credentials("alice", "secret")
+//!     .with_no_proxy("minio.internal");
+//!
+//! let client = Client::new(Region::UsEast1, Credentials::anonym()).with_proxy(proxy);
+//! ```
+
+use std::{env, fmt};
+use surf::Url;
+
+/// An outbound HTTP, HTTPS or SOCKS proxy.
+///
+/// See the [module documentation](self).
+#[derive(Clone, PartialEq, Eq)]
+pub struct Proxy {
+    url: Option<Url>,
+    credentials: Option<(String, String)>,
+    no_proxy: Vec<String>,
+}
+
+/// A possible error value when parsing a proxy URL.
+pub struct InvalidProxy {
+    reason: &'static str,
+}
+
+impl Proxy {
+    /// The URL schemes of supported proxies.
+    pub const SCHEMES: [&'static str; 6] =
+        ["http", "https", "socks4", "socks4a", "socks5", "socks5h"];
+
+    /// Returns a proxy at the given URL.
+    ///
+    /// The scheme selects the proxy protocol and must be one of
+    /// [`Proxy::SCHEMES`]. A URL without scheme refers to an
+    /// HTTP proxy. With `socks5h` and `socks4a`, host names
+    /// are resolved by the proxy.
+    ///
+    /// # Example
+    /// ```
+    /// use minio::s3::proxy::Proxy;
+    ///
+    /// let proxy = Proxy::new("socks5h://127.0.0.1:1080").unwrap();
+    /// assert_eq!("socks5h", proxy.url().unwrap().scheme());
+    ///
+    /// let proxy = Proxy::new("proxy.example.com:3128").unwrap();
+    /// assert_eq!("http", proxy.url().unwrap().scheme());
+    ///
+    /// assert!(Proxy::new("ftp://proxy.example.com").is_err());
+    /// ```
+    pub fn new(url: impl AsRef<str>) -> Result<Self, InvalidProxy> {
+        let url = url.as_ref();
+        let url = if url.contains("://") {
+            Url::parse(url)
+        } else {
+            Url::parse(&format!("http://{}", url))
+        }
+        .map_err(|_| InvalidProxy::new("malformed URL"))?;
+        if !Self::SCHEMES.contains(&url.scheme()) {
+            return Err(InvalidProxy::new("unsupported scheme"));
+        }
+        if url.host_str().is_none() {
+            return Err(InvalidProxy::new("missing host"));
+        }
+        Ok(Self {
+            url: Some(url),
+            credentials: None,
+            no_proxy: Vec::new(),
+        })
+    }
+
+    /// Returns a proxy setting that sends all requests directly,
+    /// ignoring the proxy environment variables.
+    pub fn none() -> Self {
+        Self {
+            url: None,
+            credentials: None,
+            no_proxy: Vec::new(),
+        }
+    }
+
+    /// Returns the proxy for requests to `url` according to
+    /// the environment variables, if any.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use minio::s3::proxy::Proxy;
+    /// use surf::Url;
+    ///
+    /// let url = Url::parse("https://s3.amazonaws.com").unwrap();
+    /// if let Some(proxy) = Proxy::from_env(&url) {
+    ///     println!("{}", proxy.url().unwrap());
+    /// }
+    /// ```
+    pub fn from_env(url: &Url) -> Option<Self> {
+        let names: &[&str] = match url.scheme() {
+            "https" => &["https_proxy", "HTTPS_PROXY", "all_proxy", "ALL_PROXY"],
+            _ => &["http_proxy", "HTTP_PROXY", "all_proxy", "ALL_PROXY"],
+        };
+        let proxy = names
+            .iter()
+            .find_map(|name| var(name))
+            .and_then(|url| Self::new(url).ok())?;
+        let proxy = match var("no_proxy").or_else(|| var("NO_PROXY")) {
+            Some(hosts) => hosts.split(',').fold(proxy, Self::with_no_proxy),
+            None => proxy,
+        };
+        proxy.applies_to(url).then_some(proxy)
+    }
+
+    /// Sets the user name and password the proxy requires.
+    ///
+    /// The credentials are sent using HTTP basic authentication.
+    /// Credentials embedded in the proxy URL are used as well.
+    pub fn with_credentials(
+        mut self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.credentials = Some((username.into(), password.into()));
+        self
+    }
+
+    /// Excludes the given host, and all its subdomains, from
+    /// the proxy. Requests to it are sent directly.
+    ///
+    /// A `*` excludes all hosts.
+    pub fn with_no_proxy(mut self, host: impl AsRef<str>) -> Self {
+        let host = host.as_ref().trim().trim_start_matches('.');
+        if !host.is_empty() {
+            self.no_proxy.push(host.to_ascii_lowercase());
+        }
+        self
+    }
+
+    /// Returns the URL of the proxy, or `None` if requests
+    /// are sent directly.
+    #[inline]
+    pub fn url(&self) -> Option<&Url> {
+        self.url.as_ref()
+    }
+
+    /// Returns the user name for the proxy, if any.
+    #[inline]
+    pub fn username(&self) -> Option<&str> {
+        self.credentials
+            .as_ref()
+            .map(|(username, _)| username.as_str())
+    }
+
+    /// Returns the hosts that are excluded from the proxy.
+    #[inline]
+    pub fn no_proxy(&self) -> &[String] {
+        &self.no_proxy
+    }
+
+    /// Reports whether requests to `url` are sent via the proxy.
+    ///
+    /// # Example
+    /// ```
+    /// use minio::s3::proxy::Proxy;
+    /// use surf::Url;
+    ///
+    /// let proxy = Proxy::new("http://proxy:3128").unwrap().with_no_proxy(".internal");
+    ///
+    /// assert!(proxy.applies_to(&Url::parse("https://s3.amazonaws.com").unwrap()));
+    /// assert!(!proxy.applies_to(&Url::parse("http://minio.internal:9000").unwrap()));
+    /// ```
+    pub fn applies_to(&self, url: &Url) -> bool {
+        let host = match (&self.url, url.host_str()) {
+            (Some(_), Some(host)) => host.to_ascii_lowercase(),
+            _ => return false,
+        };
+        !self.no_proxy.iter().any(|excluded| {
+            excluded == "*"
+                || host == *excluded
+                || (host.ends_with(excluded.as_str())
+                    && host[..host.len() - excluded.len()].ends_with('.'))
+        })
+    }
+
+    /// Returns the password for the proxy, if any.
+    pub(crate) fn password(&self) -> Option<&str> {
+        self.credentials
+            .as_ref()
+            .map(|(_, password)| password.as_str())
+    }
+}
+
+/// Returns the value of the environment variable `name`,
+/// unless it is unset or empty.
+fn var(name: &str) -> Option<String> {
+    env::var(name).ok().filter(|value| !value.trim().is_empty())
+}
+
+impl fmt::Debug for Proxy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Proxy")
+            .field("url", &self.url.as_ref().map(Url::as_str))
+            .field("username", &self.username())
+            .field("no_proxy", &self.no_proxy)
+            .finish()
+    }
+}
+
+impl InvalidProxy {
+    fn new(reason: &'static str) -> Self {
+        Self { reason }
+    }
+}
+
+impl std::error::Error for InvalidProxy {}
+
+impl fmt::Display for InvalidProxy {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid proxy: {}", self.reason)
+    }
+}
+
+impl fmt::Debug for InvalidProxy {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("InvalidProxy")
+            .field("reason", &self.reason)
+            .finish()
+    }
+}
@@ -33,20 +33,127 @@ use std::{convert::TryFrom, fmt, str::FromStr};
 /// let region = Region::custom_with_region("https://s3.example.com", "us-east-1").unwrap();
 /// assert_eq!("https://s3.example.com", region.endpoint());
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 #[non_exhaustive]
 pub enum Region {
+    #[default]
     UsEast1,
     UsEast2,
     UsWest1,
     UsWest2,
-    Custom { region: Custom },
+    AfSouth1,
+    ApEast1,
+    ApEast2,
+    ApNortheast1,
+    ApNortheast2,
+    ApNortheast3,
+    ApSouth1,
+    ApSouth2,
+    ApSoutheast1,
+    ApSoutheast2,
+    ApSoutheast3,
+    ApSoutheast4,
+    ApSoutheast5,
+    ApSoutheast6,
+    ApSoutheast7,
+    CaCentral1,
+    CaWest1,
+    EuCentral1,
+    EuCentral2,
+    EuNorth1,
+    EuSouth1,
+    EuSouth2,
+    EuWest1,
+    EuWest2,
+    EuWest3,
+    IlCentral1,
+    MeCentral1,
+    MeSouth1,
+    MxCentral1,
+    SaEast1,
+    CnNorth1,
+    CnNorthwest1,
+    UsGovEast1,
+    UsGovWest1,
+    Custom {
+        region: Custom,
+    },
+}
+
+/// An AWS partition.
+///
+/// AWS regions are grouped into partitions. Each partition has
+/// its own domain and credentials are only valid within the
+/// partition they have been issued for.
+///
+/// # Example
+/// ```
+/// use minio::s3::{region::Partition, Region};
+///
+/// let region: Region = "cn-north-1".parse().unwrap();
+/// assert_eq!(Some(Partition::AwsCn), region.partition());
+/// assert_eq!("amazonaws.com.cn", Partition::AwsCn.dns_suffix());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Partition {
+    /// The AWS commercial regions.
+    Aws,
+
+    /// The AWS China regions.
+    AwsCn,
+
+    /// The AWS GovCloud (US) regions.
+    AwsUsGov,
+}
+
+impl Partition {
+    /// Returns the partition of the given region name.
+    ///
+    /// Region names outside the China and GovCloud (US)
+    /// partitions belong to the `aws` partition.
+    pub fn of(region: &str) -> Self {
+        if region.starts_with("cn-") {
+            Self::AwsCn
+        } else if region.starts_with("us-gov-") {
+            Self::AwsUsGov
+        } else {
+            Self::Aws
+        }
+    }
+
+    /// Returns the domain of the partition's endpoints.
+    pub fn dns_suffix(&self) -> &'static str {
+        match *self {
+            Self::Aws | Self::AwsUsGov => "amazonaws.com",
+            Self::AwsCn => "amazonaws.com.cn",
+        }
+    }
+
+    /// Returns the partition identifier used in ARNs.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Self::Aws => "aws",
+            Self::AwsCn => "aws-cn",
+            Self::AwsUsGov => "aws-us-gov",
+        }
+    }
+}
+
+impl fmt::Display for Partition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 /// A custom S3 `Region`.
+///
+/// A custom region has at least one endpoint. Any additional
+/// endpoints are served by the same S3 deployment - for example
+/// multiple gateway nodes of a MinIO cluster.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Custom {
-    endpoint: String,
+    endpoints: Vec<String>,
     region: Option<String>,
 }
 
@@ -64,16 +171,24 @@ impl Custom {
     /// scheme and port.
     #[inline]
     pub fn endpoint(&self) -> &str {
-        &self.endpoint
+        &self.endpoints[0]
+    }
+
+    /// Returns all S3 endpoints of this region. The first
+    /// endpoint is the one returned by [`Custom::endpoint`].
+    #[inline]
+    pub fn endpoints(&self) -> &[String] {
+        &self.endpoints
     }
 
     /// Returns the host name of the S3 region endpoint without
     /// any protocol scheme or port.
     #[inline]
     pub fn host(&self) -> &str {
-        let endpoint = match self.endpoint.find("://") {
-            Some(n) => &self.endpoint[n + 3..],
-            None => &self.endpoint,
+        let endpoint = self.endpoint();
+        let endpoint = match endpoint.find("://") {
+            Some(n) => &endpoint[n + 3..],
+            None => endpoint,
         };
         match endpoint.find(":") {
             Some(n) => &endpoint[..n],
@@ -117,7 +232,7 @@ impl Region {
     pub fn custom(endpoint: impl AsRef<str>) -> Result<Self, InvalidRegion> {
         Ok(Self::Custom {
             region: Custom {
-                endpoint: parse_custom_endpoint(endpoint.as_ref())?,
+                endpoints: vec![parse_custom_endpoint(endpoint.as_ref())?],
                 region: None,
             },
         })
@@ -145,7 +260,48 @@ impl Region {
     ) -> Result<Self, InvalidRegion> {
         Ok(Self::Custom {
             region: Custom {
-                endpoint: parse_custom_endpoint(endpoint.as_ref())?,
+                endpoints: vec![parse_custom_endpoint(endpoint.as_ref())?],
+                region: Some(String::from(region.as_ref())),
+            },
+        })
+    }
+
+    /// Returns a custom S3 region served by multiple endpoints
+    /// and the given region `&str`.
+    ///
+    /// The first endpoint is the primary endpoint of the region.
+    /// It returns an error if no endpoint is given or if any
+    /// endpoint is invalid.
+    ///
+    /// # Example
+    /// ```
+    /// use minio::s3::Region;
+    ///
+    /// let region = Region::custom_with_endpoints(
+    ///     ["http://minio-1:9000", "http://minio-2:9000"],
+    ///     "us-east-1",
+    /// ).unwrap();
+    /// assert_eq!("http://minio-1:9000", region.endpoint());
+    /// assert_eq!(vec!["http://minio-1:9000", "http://minio-2:9000"], region.endpoints());
+    /// ```
+    pub fn custom_with_endpoints<I>(
+        endpoints: I,
+        region: impl AsRef<str>,
+    ) -> Result<Self, InvalidRegion>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let endpoints = endpoints
+            .into_iter()
+            .map(|endpoint| parse_custom_endpoint(endpoint.as_ref()))
+            .collect::<Result<Vec<String>, InvalidRegion>>()?;
+        if endpoints.is_empty() {
+            return Err(InvalidRegion { _priv: () });
+        }
+        Ok(Self::Custom {
+            region: Custom {
+                endpoints,
                 region: Some(String::from(region.as_ref())),
             },
         })
@@ -177,10 +333,107 @@ impl Region {
             UsEast2 => "https://s3-us-east-2.amazonaws.com",
             UsWest1 => "https://s3-us-west-1.amazonaws.com",
             UsWest2 => "https://s3-us-west-2.amazonaws.com",
+            AfSouth1 => "https://s3.af-south-1.amazonaws.com",
+            ApEast1 => "https://s3.ap-east-1.amazonaws.com",
+            ApEast2 => "https://s3.ap-east-2.amazonaws.com",
+            ApNortheast1 => "https://s3.ap-northeast-1.amazonaws.com",
+            ApNortheast2 => "https://s3.ap-northeast-2.amazonaws.com",
+            ApNortheast3 => "https://s3.ap-northeast-3.amazonaws.com",
+            ApSouth1 => "https://s3.ap-south-1.amazonaws.com",
+            ApSouth2 => "https://s3.ap-south-2.amazonaws.com",
+            ApSoutheast1 => "https://s3.ap-southeast-1.amazonaws.com",
+            ApSoutheast2 => "https://s3.ap-southeast-2.amazonaws.com",
+            ApSoutheast3 => "https://s3.ap-southeast-3.amazonaws.com",
+            ApSoutheast4 => "https://s3.ap-southeast-4.amazonaws.com",
+            ApSoutheast5 => "https://s3.ap-southeast-5.amazonaws.com",
+            ApSoutheast6 => "https://s3.ap-southeast-6.amazonaws.com",
+            ApSoutheast7 => "https://s3.ap-southeast-7.amazonaws.com",
+            CaCentral1 => "https://s3.ca-central-1.amazonaws.com",
+            CaWest1 => "https://s3.ca-west-1.amazonaws.com",
+            EuCentral1 => "https://s3.eu-central-1.amazonaws.com",
+            EuCentral2 => "https://s3.eu-central-2.amazonaws.com",
+            EuNorth1 => "https://s3.eu-north-1.amazonaws.com",
+            EuSouth1 => "https://s3.eu-south-1.amazonaws.com",
+            EuSouth2 => "https://s3.eu-south-2.amazonaws.com",
+            EuWest1 => "https://s3.eu-west-1.amazonaws.com",
+            EuWest2 => "https://s3.eu-west-2.amazonaws.com",
+            EuWest3 => "https://s3.eu-west-3.amazonaws.com",
+            IlCentral1 => "https://s3.il-central-1.amazonaws.com",
+            MeCentral1 => "https://s3.me-central-1.amazonaws.com",
+            MeSouth1 => "https://s3.me-south-1.amazonaws.com",
+            MxCentral1 => "https://s3.mx-central-1.amazonaws.com",
+            SaEast1 => "https://s3.sa-east-1.amazonaws.com",
+            CnNorth1 => "https://s3.cn-north-1.amazonaws.com.cn",
+            CnNorthwest1 => "https://s3.cn-northwest-1.amazonaws.com.cn",
+            UsGovEast1 => "https://s3.us-gov-east-1.amazonaws.com",
+            UsGovWest1 => "https://s3.us-gov-west-1.amazonaws.com",
             Custom { ref region } => region.endpoint(),
         }
     }
 
+    /// Returns all S3 endpoints of this region.
+    ///
+    /// Only custom regions may have more than one endpoint.
+    /// The first endpoint is always the one returned by
+    /// [`Region::endpoint`].
+    ///
+    /// # Example
+    /// ```
+    /// use minio::s3::Region;
+    ///
+    /// let region = Region::UsEast1;
+    /// assert_eq!(vec!["https://s3.amazonaws.com"], region.endpoints());
+    /// ```
+    pub fn endpoints(&self) -> Vec<&str> {
+        match *self {
+            self::Region::Custom { ref region } => {
+                region.endpoints().iter().map(String::as_str).collect()
+            }
+            _ => vec![self.endpoint()],
+        }
+    }
+
+    /// Returns the AWS partition of this region, or `None`
+    /// for custom regions.
+    ///
+    /// # Example
+    /// ```
+    /// use minio::s3::{region::Partition, Region};
+    ///
+    /// assert_eq!(Some(Partition::Aws), Region::UsEast1.partition());
+    /// assert_eq!(Some(Partition::AwsUsGov), Region::UsGovWest1.partition());
+    /// assert_eq!(None, Region::custom("localhost:9000").unwrap().partition());
+    /// ```
+    pub fn partition(&self) -> Option<Partition> {
+        match *self {
+            self::Region::Custom { .. } => None,
+            _ => Some(Partition::of(&self.to_string())),
+        }
+    }
+
+    /// Returns the dual-stack endpoint of this region, which
+    /// is reachable via IPv4 and IPv6, or `None` for custom
+    /// regions.
+    ///
+    /// # Example
+    /// ```
+    /// use minio::s3::Region;
+    ///
+    /// assert_eq!(
+    ///     Some("https://s3.dualstack.us-west-2.amazonaws.com"),
+    ///     Region::UsWest2.dual_stack_endpoint().as_deref(),
+    /// );
+    /// assert_eq!(
+    ///     Some("https://s3.dualstack.cn-north-1.amazonaws.com.cn"),
+    ///     Region::CnNorth1.dual_stack_endpoint().as_deref(),
+    /// );
+    /// assert_eq!(None, Region::custom("localhost:9000").unwrap().dual_stack_endpoint());
+    /// ```
+    pub fn dual_stack_endpoint(&self) -> Option<String> {
+        self.partition()
+            .map(|partition| format!("https://s3.dualstack.{}.{}", self, partition.dns_suffix()))
+    }
+
     /// Returns the host name of the S3 region endpoint.
     ///
     /// # Example
@@ -203,7 +456,7 @@ impl Region {
                 let endpoint = self.endpoint();
                 let endpoint = match endpoint.find("://") {
                     Some(n) => &endpoint[n + 3..],
-                    None => &endpoint,
+                    None => endpoint,
                 };
                 match endpoint.find(":") {
                     Some(n) => &endpoint[..n],
@@ -214,12 +467,6 @@ impl Region {
     }
 }
 
-impl Default for Region {
-    fn default() -> Self {
-        Self::UsEast1
-    }
-}
-
 impl fmt::Display for Region {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use self::Region::*;
@@ -228,6 +475,40 @@ impl fmt::Display for Region {
             UsEast2 => f.write_str("us-east-2"),
             UsWest1 => f.write_str("us-west-1"),
             UsWest2 => f.write_str("us-west-2"),
+            AfSouth1 => f.write_str("af-south-1"),
+            ApEast1 => f.write_str("ap-east-1"),
+            ApEast2 => f.write_str("ap-east-2"),
+            ApNortheast1 => f.write_str("ap-northeast-1"),
+            ApNortheast2 => f.write_str("ap-northeast-2"),
+            ApNortheast3 => f.write_str("ap-northeast-3"),
+            ApSouth1 => f.write_str("ap-south-1"),
+            ApSouth2 => f.write_str("ap-south-2"),
+            ApSoutheast1 => f.write_str("ap-southeast-1"),
+            ApSoutheast2 => f.write_str("ap-southeast-2"),
+            ApSoutheast3 => f.write_str("ap-southeast-3"),
+            ApSoutheast4 => f.write_str("ap-southeast-4"),
+            ApSoutheast5 => f.write_str("ap-southeast-5"),
+            ApSoutheast6 => f.write_str("ap-southeast-6"),
+            ApSoutheast7 => f.write_str("ap-southeast-7"),
+            CaCentral1 => f.write_str("ca-central-1"),
+            CaWest1 => f.write_str("ca-west-1"),
+            EuCentral1 => f.write_str("eu-central-1"),
+            EuCentral2 => f.write_str("eu-central-2"),
+            EuNorth1 => f.write_str("eu-north-1"),
+            EuSouth1 => f.write_str("eu-south-1"),
+            EuSouth2 => f.write_str("eu-south-2"),
+            EuWest1 => f.write_str("eu-west-1"),
+            EuWest2 => f.write_str("eu-west-2"),
+            EuWest3 => f.write_str("eu-west-3"),
+            IlCentral1 => f.write_str("il-central-1"),
+            MeCentral1 => f.write_str("me-central-1"),
+            MeSouth1 => f.write_str("me-south-1"),
+            MxCentral1 => f.write_str("mx-central-1"),
+            SaEast1 => f.write_str("sa-east-1"),
+            CnNorth1 => f.write_str("cn-north-1"),
+            CnNorthwest1 => f.write_str("cn-northwest-1"),
+            UsGovEast1 => f.write_str("us-gov-east-1"),
+            UsGovWest1 => f.write_str("us-gov-west-1"),
             Custom { ref region } => match region.region() {
                 Some(region) => f.write_str(region),
                 None => Ok(()),
@@ -246,6 +527,40 @@ impl FromStr for Region {
             "us-east-2" => Ok(Self::UsEast2),
             "us-west-1" => Ok(Self::UsWest1),
             "us-west-2" => Ok(Self::UsWest2),
+            "af-south-1" => Ok(Self::AfSouth1),
+            "ap-east-1" => Ok(Self::ApEast1),
+            "ap-east-2" => Ok(Self::ApEast2),
+            "ap-northeast-1" => Ok(Self::ApNortheast1),
+            "ap-northeast-2" => Ok(Self::ApNortheast2),
+            "ap-northeast-3" => Ok(Self::ApNortheast3),
+            "ap-south-1" => Ok(Self::ApSouth1),
+            "ap-south-2" => Ok(Self::ApSouth2),
+            "ap-southeast-1" => Ok(Self::ApSoutheast1),
+            "ap-southeast-2" => Ok(Self::ApSoutheast2),
+            "ap-southeast-3" => Ok(Self::ApSoutheast3),
+            "ap-southeast-4" => Ok(Self::ApSoutheast4),
+            "ap-southeast-5" => Ok(Self::ApSoutheast5),
+            "ap-southeast-6" => Ok(Self::ApSoutheast6),
+            "ap-southeast-7" => Ok(Self::ApSoutheast7),
+            "ca-central-1" => Ok(Self::CaCentral1),
+            "ca-west-1" => Ok(Self::CaWest1),
+            "eu-central-1" => Ok(Self::EuCentral1),
+            "eu-central-2" => Ok(Self::EuCentral2),
+            "eu-north-1" => Ok(Self::EuNorth1),
+            "eu-south-1" => Ok(Self::EuSouth1),
+            "eu-south-2" => Ok(Self::EuSouth2),
+            "eu-west-1" => Ok(Self::EuWest1),
+            "eu-west-2" => Ok(Self::EuWest2),
+            "eu-west-3" => Ok(Self::EuWest3),
+            "il-central-1" => Ok(Self::IlCentral1),
+            "me-central-1" => Ok(Self::MeCentral1),
+            "me-south-1" => Ok(Self::MeSouth1),
+            "mx-central-1" => Ok(Self::MxCentral1),
+            "sa-east-1" => Ok(Self::SaEast1),
+            "cn-north-1" => Ok(Self::CnNorth1),
+            "cn-northwest-1" => Ok(Self::CnNorthwest1),
+            "us-gov-east-1" => Ok(Self::UsGovEast1),
+            "us-gov-west-1" => Ok(Self::UsGovWest1),
             _ => Self::custom(s),
         }
     }
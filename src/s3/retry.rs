@@ -0,0 +1,151 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+use surf::{http::Method, StatusCode};
+
+/// A retry policy for idempotent requests.
+///
+/// A `GET`, `HEAD`, `PUT` or `DELETE` request that fails with
+/// a transient error, like a transport error, a `5xx` response or
+/// `429 Too Many Requests`, is sent again, up to `max_attempts`
+/// times in total. Before each retry, the client waits for a
+/// random delay of up to `base_delay * 2^(n-1)`, limited to
+/// `max_delay`, and signs the request again with the current time.
+///
+/// See: [`Error::is_retryable`](crate::s3::Error::is_retryable)
+///
+/// Only requests whose body can be sent again are retried, i.e.
+/// requests without a body or with a body given as bytes. Streamed
+/// uploads are not retried.
+///
+/// # Example
+/// ```
+/// use minio::s3::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::new()
+///     .with_max_attempts(5)
+///     .with_base_delay(Duration::from_millis(50));
+/// assert_eq!(5, policy.max_attempts());
+/// assert!(policy.delay(3) <= Duration::from_millis(200));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// The number of attempts by default.
+    pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
+    /// The delay before the first retry by default.
+    pub const DEFAULT_BASE_DELAY: Duration = Duration::from_millis(100);
+
+    /// The longest delay between two attempts by default.
+    pub const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(5);
+
+    /// Returns a new policy with the default attempts
+    /// and delays.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of times a request is sent, including
+    /// the first attempt.
+    ///
+    /// A value of `0` is treated as `1`.
+    pub fn with_max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = attempts.max(1);
+        self
+    }
+
+    /// Sets the delay before the first retry. It doubles
+    /// with every further retry.
+    pub fn with_base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
+        self
+    }
+
+    /// Sets the longest delay between two attempts.
+    pub fn with_max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    #[inline]
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    #[inline]
+    pub fn base_delay(&self) -> Duration {
+        self.base_delay
+    }
+
+    #[inline]
+    pub fn max_delay(&self) -> Duration {
+        self.max_delay
+    }
+
+    /// Returns the time to wait before sending a request
+    /// again after the given number of failed attempts.
+    ///
+    /// The delay is chosen at random, between zero and the
+    /// exponential backoff, such that clients that failed at
+    /// the same time do not retry at the same time.
+    pub fn delay(&self, failed: u32) -> Duration {
+        let backoff = self
+            .base_delay
+            .saturating_mul(1 << failed.saturating_sub(1).min(16))
+            .min(self.max_delay);
+        let jitter = RandomState::new().build_hasher().finish();
+        backoff.mul_f64(jitter as f64 / u64::MAX as f64)
+    }
+
+    /// Reports whether requests with the given method may be
+    /// retried. Only idempotent requests are sent more than once.
+    #[inline]
+    pub(crate) fn applies_to(&self, method: Method) -> bool {
+        matches!(
+            method,
+            Method::Get | Method::Head | Method::Put | Method::Delete
+        )
+    }
+
+    /// Reports whether a response with the given status
+    /// indicates a transient failure.
+    #[inline]
+    pub(crate) fn is_retryable(status: StatusCode) -> bool {
+        status.is_server_error() || status == StatusCode::TooManyRequests
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: Self::DEFAULT_MAX_ATTEMPTS,
+            base_delay: Self::DEFAULT_BASE_DELAY,
+            max_delay: Self::DEFAULT_MAX_DELAY,
+        }
+    }
+}
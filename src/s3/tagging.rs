@@ -0,0 +1,190 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::{sv4, xml, InvalidMetadata};
+use serde_derive::Deserialize;
+use std::collections::{btree_map, BTreeMap};
+
+/// A set of S3 tags.
+///
+/// Each tag consists of a unique key and a value.
+///
+/// # Example
+/// ```
+/// use minio::s3::TagSet;
+///
+/// let mut tags = TagSet::new();
+/// tags.insert("project", "apollo");
+/// tags.insert("team", "storage");
+///
+/// assert_eq!(Some("apollo"), tags.get("project"));
+/// assert!(tags.contains_key("team"));
+/// assert_eq!(2, tags.len());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TagSet {
+    tags: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Tagging {
+    #[serde(rename(deserialize = "TagSet"), default)]
+    tag_set: TagSetXml,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct TagSetXml {
+    #[serde(rename(deserialize = "Tag"), default)]
+    tags: Vec<Tag>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Tag {
+    #[serde(rename(deserialize = "Key"))]
+    key: String,
+
+    #[serde(rename(deserialize = "Value"), default)]
+    value: String,
+}
+
+impl TagSet {
+    /// Returns a new empty `TagSet`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a tag and returns the previous value of
+    /// the tag, if any.
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) -> Option<String> {
+        self.tags.insert(key.into(), value.into())
+    }
+
+    /// Removes a tag and returns its value, if any.
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        self.tags.remove(key)
+    }
+
+    /// Returns the value of the tag `key`, if any.
+    #[inline]
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.tags.get(key).map(String::as_str)
+    }
+
+    #[inline]
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.tags.contains_key(key)
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.tags.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
+
+    /// Returns an iterator over all tags, ordered by key.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            inner: self.tags.iter(),
+        }
+    }
+}
+
+/// An iterator over the tags of a [`TagSet`].
+pub struct Iter<'a> {
+    inner: btree_map::Iter<'a, String, String>,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = (&'a str, &'a str);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+}
+
+impl<'a> IntoIterator for &'a TagSet {
+    type Item = (&'a str, &'a str);
+    type IntoIter = Iter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for TagSet
+where
+    K: Into<String>,
+    V: Into<String>,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self {
+            tags: iter
+                .into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+        }
+    }
+}
+
+/// Parses a `Tagging` document.
+pub(crate) fn from_string(s: impl AsRef<str>) -> Result<TagSet, InvalidMetadata> {
+    let tagging: Tagging =
+        serde_xml_rs::from_str(s.as_ref()).map_err(|_| InvalidMetadata::new())?;
+    Ok(tagging
+        .tag_set
+        .tags
+        .into_iter()
+        .map(|tag| (tag.key, tag.value))
+        .collect())
+}
+
+/// Returns the `Tagging` document of the tags.
+pub(crate) fn to_string(tags: &TagSet) -> String {
+    let mut xml = String::from("<Tagging><TagSet>");
+    for (key, value) in tags {
+        xml.push_str(&format!(
+            "<Tag><Key>{}</Key><Value>{}</Value></Tag>",
+            xml::escape(key),
+            xml::escape(value)
+        ));
+    }
+    xml.push_str("</TagSet></Tagging>");
+    xml
+}
+
+/// Returns the tags URL-encoded as query parameters, as
+/// expected by the `x-amz-tagging` header.
+pub(crate) fn to_query(tags: &TagSet) -> String {
+    tags.iter()
+        .map(|(key, value)| {
+            format!(
+                "{}={}",
+                sv4::uri_encode(key, true),
+                sv4::uri_encode(value, true)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
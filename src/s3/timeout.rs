@@ -0,0 +1,97 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+/// The timeouts of S3 requests.
+///
+/// By default, requests have no timeout and wait indefinitely
+/// for an unresponsive endpoint. There are three timeouts:
+///
+///  - The connect timeout limits the time it takes to establish
+///    a connection to an endpoint.
+///  - The request timeout limits the time a single attempt of a
+///    request takes until the response headers have been received.
+///    The response body is not covered.
+///  - The operation timeout limits the time a request takes
+///    including all its retries and the delays between them.
+///
+/// A request that exceeds its request or operation timeout fails
+/// with an error for which
+/// [`Error::is_timeout`](crate::s3::Error::is_timeout) returns
+/// `true`. An attempt that has timed out is retried according to
+/// the [`RetryPolicy`](crate::s3::RetryPolicy), if any.
+///
+/// # Example
+/// ```
+/// use minio::s3::{Client, Credentials, Region, Timeouts};
+/// use std::time::Duration;
+///
+/// let timeouts = Timeouts::new()
+///     .with_connect(Duration::from_secs(3))
+///     .with_request(Duration::from_secs(30))
+///     .with_operation(Duration::from_secs(120));
+/// let client = Client::new(Region::UsEast1, Credentials::anonym()).with_timeouts(timeouts);
+/// assert_eq!(Some(Duration::from_secs(3)), client.timeouts().connect());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timeouts {
+    connect: Option<Duration>,
+    request: Option<Duration>,
+    operation: Option<Duration>,
+}
+
+impl Timeouts {
+    /// Returns new timeouts that never expire.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the time it may take to connect to an endpoint.
+    pub fn with_connect(mut self, timeout: Duration) -> Self {
+        self.connect = Some(timeout);
+        self
+    }
+
+    /// Sets the time a single attempt of a request may take
+    /// until the response headers have been received.
+    pub fn with_request(mut self, timeout: Duration) -> Self {
+        self.request = Some(timeout);
+        self
+    }
+
+    /// Sets the time a request may take, including all its
+    /// retries.
+    pub fn with_operation(mut self, timeout: Duration) -> Self {
+        self.operation = Some(timeout);
+        self
+    }
+
+    #[inline]
+    pub fn connect(&self) -> Option<Duration> {
+        self.connect
+    }
+
+    #[inline]
+    pub fn request(&self) -> Option<Duration> {
+        self.request
+    }
+
+    #[inline]
+    pub fn operation(&self) -> Option<Duration> {
+        self.operation
+    }
+}
@@ -0,0 +1,264 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tracing instrumentation of S3 operations.
+//!
+//! Each request sent by a [`Client`](crate::s3::Client) runs in an
+//! `s3` span at `INFO` level with the fields:
+//!
+//! - `operation`: the name of the S3 operation, like `GetObject`.
+//! - `bucket` and `key`: the bucket and object key, if any.
+//! - `status`: the status code of the final response.
+//! - `latency_ms`: the time until the final response, in milliseconds.
+//! - `retries`: the number of retries under the client's retry policy.
+//! - `error`: the error of a failed operation.
+//!
+//! Retries, failovers to other endpoints, redirects to the bucket's
+//! region and refreshed credentials are reported as `INFO` events
+//! within the span. Signatures rejected by S3 are reported as `WARN`
+//! events.
+
+use crate::s3::{addressing, ErrorCode, Region, Result};
+use percent_encoding::percent_decode_str;
+use std::{future::Future, time::Instant};
+use surf::{
+    http::{Method, Request},
+    Response, StatusCode, Url,
+};
+use tracing::{field, Instrument, Span};
+
+/// The S3 operation a request performs.
+struct Operation {
+    name: String,
+    bucket: Option<String>,
+    key: Option<String>,
+}
+
+/// Returns the span of the operation the request performs.
+///
+/// The endpoint is the one the request was built for. Requests
+/// for other hosts have no bucket or key.
+pub(crate) fn span(request: &Request, endpoint: &str) -> Span {
+    let operation = operation(request, endpoint);
+    tracing::info_span!(
+        "s3",
+        operation = %operation.name,
+        bucket = operation.bucket.as_deref(),
+        key = operation.key.as_deref(),
+        status = field::Empty,
+        latency_ms = field::Empty,
+        retries = field::Empty,
+        error = field::Empty,
+    )
+}
+
+/// Runs the operation in its span and records its outcome.
+pub(crate) async fn instrument(
+    span: Span,
+    operation: impl Future<Output = Result<Response>>,
+) -> Result<Response> {
+    let start = Instant::now();
+    let mut result = operation.instrument(span.clone()).await;
+    finish(&span, start, &mut result).await;
+    result
+}
+
+/// Records the outcome of the operation in its span.
+///
+/// Responses rejecting the signature of the request are reported.
+/// Their body is restored after inspecting it.
+async fn finish(span: &Span, start: Instant, result: &mut Result<Response>) {
+    span.record("latency_ms", start.elapsed().as_millis() as u64);
+    match result {
+        Ok(response) => {
+            span.record("status", u16::from(response.status()));
+            if response.status() != StatusCode::Forbidden {
+                return;
+            }
+            let body = match response.body_string().await {
+                Ok(body) => body,
+                Err(err) => {
+                    span.record("error", field::display(&err));
+                    return;
+                }
+            };
+            if body.contains("<Code>SignatureDoesNotMatch</Code>") {
+                span.in_scope(|| tracing::warn!("signature does not match"));
+            }
+            response.set_body(body);
+        }
+        Err(err) => {
+            span.record("error", field::display(&*err));
+            if err.code() == Some(ErrorCode::SignatureDoesNotMatch) {
+                span.in_scope(|| tracing::warn!(error = %err, "signature does not match"));
+            }
+        }
+    }
+}
+
+/// Reports that a request is sent again under the retry policy.
+pub(crate) fn retry(retries: u32, delay_ms: u64, cause: &Result<Response>) {
+    Span::current().record("retries", retries);
+    match cause {
+        Ok(response) => {
+            let status = u16::from(response.status());
+            tracing::info!(retries, delay_ms, status, "retrying request");
+        }
+        Err(err) => tracing::info!(retries, delay_ms, error = %err, "retrying request"),
+    }
+}
+
+/// Reports that a request is sent to the next endpoint since
+/// the endpoint is not reachable.
+pub(crate) fn failover(endpoint: &str, err: &crate::s3::Error) {
+    tracing::info!(endpoint, error = %err, "endpoint unreachable");
+}
+
+/// Reports that a request is sent again to the region of its bucket.
+pub(crate) fn redirect(region: &Region) {
+    tracing::info!(region = %region, "retrying request in bucket region");
+}
+
+/// Reports that a request is sent again with refreshed credentials.
+pub(crate) fn refresh() {
+    tracing::info!("credentials expired, retrying request with refreshed credentials");
+}
+
+/// Returns the operation the request performs.
+///
+/// The name is derived from the method, the subresource in the
+/// query and the presence of a bucket and key, as in the S3 API
+/// reference.
+fn operation(request: &Request, endpoint: &str) -> Operation {
+    let url = request.url();
+    let (bucket, key) = target(url, endpoint);
+    let method = request.method();
+    let query = |name: &str| url.query_pairs().any(|(key, _)| key == name);
+    let copy = request.header("x-amz-copy-source").is_some();
+    let verb = match method {
+        Method::Get => "Get",
+        Method::Put => "Put",
+        Method::Delete => "Delete",
+        _ => "",
+    };
+
+    let name = match (&bucket, &key) {
+        (None, _) if method == Method::Get => String::from("ListBuckets"),
+        (None, _) => method.to_string(),
+        (Some(_), None) => match method {
+            Method::Get if query("list-type") => String::from("ListObjectsV2"),
+            Method::Get if query("versions") => String::from("ListObjectVersions"),
+            Method::Get if query("uploads") => String::from("ListMultipartUploads"),
+            Method::Post if query("delete") => String::from("DeleteObjects"),
+            Method::Head => String::from("HeadBucket"),
+            _ => match subresource(url, BUCKET_SUBRESOURCES) {
+                Some(suffix) if !verb.is_empty() => format!("{}Bucket{}", verb, suffix),
+                Some(_) => method.to_string(),
+                None => match method {
+                    Method::Get => String::from("ListObjects"),
+                    Method::Put => String::from("CreateBucket"),
+                    Method::Delete => String::from("DeleteBucket"),
+                    _ => method.to_string(),
+                },
+            },
+        },
+        (Some(_), Some(_)) => match method {
+            Method::Post if query("uploads") => String::from("CreateMultipartUpload"),
+            Method::Post if query("uploadId") => String::from("CompleteMultipartUpload"),
+            Method::Post if query("restore") => String::from("RestoreObject"),
+            Method::Post if query("select") => String::from("SelectObjectContent"),
+            Method::Put if query("uploadId") && copy => String::from("UploadPartCopy"),
+            Method::Put if query("uploadId") => String::from("UploadPart"),
+            Method::Get if query("uploadId") => String::from("ListParts"),
+            Method::Delete if query("uploadId") => String::from("AbortMultipartUpload"),
+            Method::Head => String::from("HeadObject"),
+            _ => match subresource(url, OBJECT_SUBRESOURCES) {
+                Some(suffix) if !verb.is_empty() => format!("{}Object{}", verb, suffix),
+                Some(_) => method.to_string(),
+                None if copy && method == Method::Put => String::from("CopyObject"),
+                None if !verb.is_empty() => format!("{}Object", verb),
+                None => method.to_string(),
+            },
+        },
+    };
+    Operation { name, bucket, key }
+}
+
+/// Returns the bucket and key of the request URL, in path or
+/// virtual-hosted style for the endpoint.
+fn target(url: &Url, endpoint: &str) -> (Option<String>, Option<String>) {
+    let decode = |s: &str| percent_decode_str(s).decode_utf8_lossy().into_owned();
+    let path = url.path().trim_start_matches('/');
+    let (bucket, key) = match addressing::subdomain(url, endpoint) {
+        Some(subdomain) => (subdomain.trim_end_matches('.').to_owned(), path),
+        None if addressing::targets(url, endpoint) && url.host_str().is_some() => {
+            match path.split_once('/') {
+                Some((bucket, key)) => (decode(bucket), key),
+                None => (decode(path), ""),
+            }
+        }
+        None => return (None, None),
+    };
+    match (bucket.is_empty(), key.is_empty()) {
+        (true, _) => (None, None),
+        (false, true) => (Some(bucket), None),
+        (false, false) => (Some(bucket), Some(decode(key))),
+    }
+}
+
+/// Returns the operation suffix of the first subresource
+/// in the query of the URL, if any.
+fn subresource(url: &Url, subresources: &[(&str, &'static str)]) -> Option<&'static str> {
+    url.query_pairs().find_map(|(key, _)| {
+        subresources
+            .iter()
+            .find(|(name, _)| *name == key)
+            .map(|(_, suffix)| *suffix)
+    })
+}
+
+const BUCKET_SUBRESOURCES: &[(&str, &str)] = &[
+    ("accelerate", "AccelerateConfiguration"),
+    ("acl", "Acl"),
+    ("analytics", "AnalyticsConfiguration"),
+    ("cors", "Cors"),
+    ("encryption", "Encryption"),
+    ("intelligent-tiering", "IntelligentTieringConfiguration"),
+    ("inventory", "InventoryConfiguration"),
+    ("lifecycle", "LifecycleConfiguration"),
+    ("location", "Location"),
+    ("logging", "Logging"),
+    ("metrics", "MetricsConfiguration"),
+    ("notification", "NotificationConfiguration"),
+    ("object-lock", "ObjectLockConfiguration"),
+    ("ownershipControls", "OwnershipControls"),
+    ("policy", "Policy"),
+    ("policyStatus", "PolicyStatus"),
+    ("publicAccessBlock", "PublicAccessBlock"),
+    ("replication", "Replication"),
+    ("requestPayment", "RequestPayment"),
+    ("tagging", "Tagging"),
+    ("versioning", "Versioning"),
+    ("website", "Website"),
+];
+
+const OBJECT_SUBRESOURCES: &[(&str, &str)] = &[
+    ("acl", "Acl"),
+    ("attributes", "Attributes"),
+    ("legal-hold", "LegalHold"),
+    ("retention", "Retention"),
+    ("tagging", "Tagging"),
+    ("torrent", "Torrent"),
+];
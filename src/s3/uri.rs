@@ -0,0 +1,167 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::{BucketName, InvalidBucketName, InvalidObjectKey, ObjectKey};
+use std::{convert::TryFrom, fmt, str::FromStr};
+
+/// An S3 URI of the form `s3://<bucket>/<key>`.
+///
+/// The bucket has to be a valid [`BucketName`]. The key is
+/// optional and, if present, has to be a valid [`ObjectKey`].
+/// It is everything following the first `/` after the bucket
+/// and is not percent-decoded, like the keys of the AWS CLI.
+///
+/// # Example
+/// ```
+/// use minio::s3::S3Uri;
+///
+/// let uri: S3Uri = "s3://my-bucket/photos/beach.jpg".parse().unwrap();
+/// assert_eq!("my-bucket", uri.bucket().as_str());
+/// assert_eq!(Some("photos/beach.jpg"), uri.key().map(|key| key.as_str()));
+///
+/// let uri: S3Uri = "s3://my-bucket".parse().unwrap();
+/// assert_eq!(None, uri.key());
+///
+/// assert!("https://my-bucket/object".parse::<S3Uri>().is_err());
+/// assert!("s3://My_Bucket/object".parse::<S3Uri>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct S3Uri {
+    bucket: BucketName,
+    key: Option<ObjectKey>,
+}
+
+/// A possible error when parsing an S3 URI.
+#[derive(Clone, PartialEq, Eq)]
+pub struct InvalidS3Uri {
+    kind: Kind,
+}
+
+#[derive(Clone, PartialEq, Eq)]
+enum Kind {
+    Scheme,
+    Bucket(InvalidBucketName),
+    Key(InvalidObjectKey),
+}
+
+const SCHEME: &str = "s3://";
+
+impl S3Uri {
+    /// Returns a new `S3Uri` referring to the bucket or, if
+    /// present, to the object `key` within the bucket.
+    #[inline]
+    pub fn new(bucket: BucketName, key: Option<ObjectKey>) -> Self {
+        Self { bucket, key }
+    }
+
+    #[inline]
+    pub fn bucket(&self) -> &BucketName {
+        &self.bucket
+    }
+
+    #[inline]
+    pub fn key(&self) -> Option<&ObjectKey> {
+        self.key.as_ref()
+    }
+
+    #[inline]
+    pub fn into_parts(self) -> (BucketName, Option<ObjectKey>) {
+        (self.bucket, self.key)
+    }
+}
+
+impl fmt::Display for S3Uri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.key {
+            Some(ref key) => write!(f, "{}{}/{}", SCHEME, self.bucket, key),
+            None => write!(f, "{}{}", SCHEME, self.bucket),
+        }
+    }
+}
+
+impl FromStr for S3Uri {
+    type Err = InvalidS3Uri;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rest = match s.get(..SCHEME.len()) {
+            Some(scheme) if scheme.eq_ignore_ascii_case(SCHEME) => &s[SCHEME.len()..],
+            _ => return Err(InvalidS3Uri::new(Kind::Scheme)),
+        };
+        let (bucket, key) = match rest.split_once('/') {
+            Some((bucket, "")) => (bucket, None),
+            Some((bucket, key)) => (bucket, Some(key)),
+            None => (rest, None),
+        };
+
+        let bucket = BucketName::new(bucket).map_err(|err| InvalidS3Uri::new(Kind::Bucket(err)))?;
+        let key = match key {
+            Some(key) => {
+                Some(ObjectKey::new(key).map_err(|err| InvalidS3Uri::new(Kind::Key(err)))?)
+            }
+            None => None,
+        };
+        Ok(Self { bucket, key })
+    }
+}
+
+impl<'a> TryFrom<&'a str> for S3Uri {
+    type Error = InvalidS3Uri;
+
+    #[inline]
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+// === InvalidS3Uri ===
+
+impl InvalidS3Uri {
+    fn new(kind: Kind) -> Self {
+        Self { kind }
+    }
+}
+
+impl std::error::Error for InvalidS3Uri {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self.kind {
+            Kind::Scheme => None,
+            Kind::Bucket(ref err) => Some(err),
+            Kind::Key(ref err) => Some(err),
+        }
+    }
+}
+
+impl fmt::Display for InvalidS3Uri {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let reason = match self.kind {
+            Kind::Scheme => "URI must start with 's3://'",
+            Kind::Bucket(_) => "invalid bucket name",
+            Kind::Key(_) => "invalid object key",
+        };
+        write!(f, "invalid S3 URI: {}", reason)
+    }
+}
+
+impl fmt::Debug for InvalidS3Uri {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut debug = f.debug_struct("InvalidS3Uri");
+        match self.kind {
+            Kind::Scheme => debug.field("reason", &"URI must start with 's3://'"),
+            Kind::Bucket(ref err) => debug.field("bucket", err),
+            Kind::Key(ref err) => debug.field("key", err),
+        };
+        debug.finish()
+    }
+}
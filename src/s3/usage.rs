@@ -0,0 +1,94 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::{object::ObjectInfo, StorageClass};
+use std::collections::HashMap;
+
+/// The aggregated usage of the objects under a prefix.
+///
+/// See: [`Bucket::disk_usage`](crate::s3::Bucket::disk_usage)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Usage {
+    total: ClassUsage,
+    classes: HashMap<StorageClass, ClassUsage>,
+}
+
+/// The number of objects and their total size.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ClassUsage {
+    objects: u64,
+    size: u64,
+}
+
+impl Usage {
+    /// Returns the number of objects.
+    #[inline]
+    pub fn objects(&self) -> u64 {
+        self.total.objects
+    }
+
+    /// Returns the total size of all objects in bytes.
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.total.size
+    }
+
+    /// Returns the usage of the objects with the given storage class.
+    ///
+    /// # Example
+    /// ```
+    /// use minio::s3::{usage::Usage, StorageClass};
+    ///
+    /// let usage = Usage::default();
+    /// assert_eq!(0, usage.storage_class(StorageClass::Glacier).objects());
+    /// ```
+    pub fn storage_class(&self, class: StorageClass) -> ClassUsage {
+        self.classes.get(&class).copied().unwrap_or_default()
+    }
+
+    /// Returns the usage of all storage classes with at least
+    /// one object.
+    #[inline]
+    pub fn storage_classes(&self) -> &HashMap<StorageClass, ClassUsage> {
+        &self.classes
+    }
+
+    pub(crate) fn add(&mut self, object: &ObjectInfo) {
+        self.total.add(object.size());
+        self.classes
+            .entry(object.storage_class())
+            .or_default()
+            .add(object.size());
+    }
+}
+
+impl ClassUsage {
+    /// Returns the number of objects.
+    #[inline]
+    pub fn objects(&self) -> u64 {
+        self.objects
+    }
+
+    /// Returns the total size of all objects in bytes.
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    fn add(&mut self, size: u64) {
+        self.objects += 1;
+        self.size = self.size.saturating_add(size);
+    }
+}
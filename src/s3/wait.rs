@@ -0,0 +1,118 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::{error, Result};
+use async_std::task;
+use std::{
+    future::Future,
+    time::{Duration, Instant},
+};
+
+/// A polling policy for waiting until a resource reaches
+/// an expected state.
+///
+/// A waiter checks the state of the resource repeatedly. The
+/// delay between two checks starts at the initial delay and
+/// doubles after every check, up to the maximum delay. The
+/// waiter gives up with a timeout error once the timeout has
+/// elapsed.
+///
+/// # Example
+/// ```
+/// use minio::s3::WaitPolicy;
+/// use std::time::Duration;
+///
+/// let policy = WaitPolicy::new(Duration::from_secs(60))
+///     .with_delay(Duration::from_millis(500), Duration::from_secs(10));
+///
+/// assert_eq!(Duration::from_secs(60), policy.timeout());
+/// assert_eq!(Duration::from_millis(500), policy.initial_delay());
+/// assert_eq!(Duration::from_secs(10), policy.max_delay());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WaitPolicy {
+    timeout: Duration,
+    initial_delay: Duration,
+    max_delay: Duration,
+}
+
+impl WaitPolicy {
+    /// Returns a new policy that gives up after the given timeout.
+    ///
+    /// By default, the delay between two checks grows from 1
+    /// second to at most 30 seconds.
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+
+    /// Sets the delay before the second check and the
+    /// maximum delay between two checks.
+    ///
+    /// The maximum delay is at least the initial delay.
+    pub fn with_delay(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_delay = initial;
+        self.max_delay = max.max(initial);
+        self
+    }
+
+    #[inline]
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    #[inline]
+    pub fn initial_delay(&self) -> Duration {
+        self.initial_delay
+    }
+
+    #[inline]
+    pub fn max_delay(&self) -> Duration {
+        self.max_delay
+    }
+
+    /// Checks the condition until it holds or the policy
+    /// times out. Errors returned by the condition are
+    /// returned immediately.
+    pub(crate) async fn until<F, Fut>(&self, mut condition: F) -> Result<()>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<bool>>,
+    {
+        let deadline = Instant::now() + self.timeout;
+        let mut delay = self.initial_delay;
+        loop {
+            if condition().await? {
+                return Ok(());
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(error::timeout());
+            }
+            task::sleep(delay.min(deadline - now)).await;
+            delay = (delay * 2).min(self.max_delay);
+        }
+    }
+}
+
+impl Default for WaitPolicy {
+    /// Returns a policy that gives up after 5 minutes.
+    fn default() -> Self {
+        Self::new(Duration::from_secs(5 * 60))
+    }
+}
@@ -0,0 +1,84 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::{Etag, ObjectInfo};
+use std::collections::{BTreeMap, VecDeque};
+
+/// A change of an object detected by
+/// [`Bucket::watch`](crate::s3::Bucket::watch).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// A new object has been created.
+    Created(ObjectInfo),
+
+    /// An existing object has been overwritten with
+    /// different content, i.e. its ETag has changed.
+    Modified(ObjectInfo),
+
+    /// The object with the given key has been deleted.
+    Deleted(String),
+}
+
+impl Event {
+    /// Returns the key of the object that has changed.
+    pub fn key(&self) -> &str {
+        match self {
+            Self::Created(object) | Self::Modified(object) => object.key(),
+            Self::Deleted(key) => key,
+        }
+    }
+}
+
+/// The listing state of a watched prefix.
+#[derive(Debug, Default)]
+pub(crate) struct Snapshot {
+    objects: Option<BTreeMap<String, Etag>>,
+}
+
+impl Snapshot {
+    /// Replaces the snapshot with the given listing and returns
+    /// the changes, ordered by key.
+    ///
+    /// The first listing only establishes the baseline and does
+    /// not produce any events.
+    pub(crate) fn update(&mut self, listing: Vec<ObjectInfo>) -> VecDeque<Event> {
+        let current: BTreeMap<String, Etag> = listing
+            .iter()
+            .map(|object| (String::from(object.key()), *object.etag()))
+            .collect();
+        let previous = match self.objects.replace(current) {
+            Some(previous) => previous,
+            None => return VecDeque::new(),
+        };
+
+        let mut events: Vec<Event> = Vec::new();
+        for object in listing {
+            match previous.get(object.key()) {
+                None => events.push(Event::Created(object)),
+                Some(etag) if etag != object.etag() => events.push(Event::Modified(object)),
+                Some(_) => (),
+            }
+        }
+        let current = self.objects.as_ref().expect("snapshot of current listing");
+        events.extend(
+            previous
+                .into_keys()
+                .filter(|key| !current.contains_key(key))
+                .map(Event::Deleted),
+        );
+        events.sort_by(|a, b| a.key().cmp(b.key()));
+        events.into()
+    }
+}
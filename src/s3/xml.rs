@@ -0,0 +1,238 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_std::io::{self, Read, ReadExt};
+use std::collections::HashMap;
+
+/// The number of bytes read from the underlying reader at once.
+const CHUNK_SIZE: usize = 16 * 1024;
+
+/// An incremental reader for XML documents that consist of
+/// a root element with many, possibly repeated, children.
+///
+/// It returns the children with one of the given names one
+/// by one, as soon as they have been read completely. All
+/// other children are treated as fields and only their text
+/// content is kept. Hence, the memory required is bounded
+/// by the size of the largest child instead of the size of
+/// the document.
+///
+/// The reader does not validate the document. It only
+/// supports the XML subset used by S3 responses.
+pub(crate) struct Reader<R> {
+    inner: R,
+    elements: &'static [&'static str],
+
+    buf: Vec<u8>,
+    pos: usize,
+    depth: usize,
+    child: Option<Child>,
+    fields: HashMap<String, String>,
+    eof: bool,
+}
+
+/// A child of the root element that is being read.
+struct Child {
+    name: String,
+    start: usize,
+    content: usize,
+}
+
+enum Tag<'a> {
+    Start(&'a str),
+    End,
+    Empty(&'a str),
+    Other,
+}
+
+impl<R: Read + Unpin> Reader<R> {
+    /// Returns a new reader that returns all children
+    /// of the root element with one of the given names.
+    pub(crate) fn new(inner: R, elements: &'static [&'static str]) -> Self {
+        Self {
+            inner,
+            elements,
+            buf: Vec::new(),
+            pos: 0,
+            depth: 0,
+            child: None,
+            fields: HashMap::new(),
+            eof: false,
+        }
+    }
+
+    /// Returns the text content of the field `name`, if it
+    /// has been read already.
+    ///
+    /// Fields that follow the last returned element are only
+    /// available once `next` has returned `None`.
+    pub(crate) fn field(&self, name: &str) -> Option<&str> {
+        self.fields.get(name).map(String::as_str)
+    }
+
+    /// Returns the name and XML of the next element, or `None`
+    /// at the end of the document.
+    pub(crate) async fn next(&mut self) -> io::Result<Option<(String, String)>> {
+        loop {
+            if let Some(element) = self.scan()? {
+                return Ok(Some(element));
+            }
+            if self.eof {
+                return match self.depth {
+                    0 => Ok(None),
+                    _ => Err(invalid_data("unexpected end of XML document")),
+                };
+            }
+            self.compact();
+
+            let len = self.buf.len();
+            self.buf.resize(len + CHUNK_SIZE, 0);
+            let n = self.inner.read(&mut self.buf[len..]).await?;
+            self.buf.truncate(len + n);
+            self.eof = n == 0;
+        }
+    }
+
+    /// Processes all complete tags in the buffer until the
+    /// next element has been read.
+    fn scan(&mut self) -> io::Result<Option<(String, String)>> {
+        loop {
+            let start = match find(&self.buf[self.pos..], b"<") {
+                Some(n) => self.pos + n,
+                None => return Ok(None),
+            };
+            let terminator: &[u8] = if self.buf[start..].starts_with(b"<!--") {
+                b"-->"
+            } else {
+                b">"
+            };
+            let end = match find(&self.buf[start..], terminator) {
+                Some(n) => start + n + terminator.len(),
+                None => return Ok(None),
+            };
+            self.pos = end;
+
+            let tag = std::str::from_utf8(&self.buf[start..end])
+                .map_err(|_| invalid_data("XML document is not valid UTF-8"))?;
+            match parse_tag(tag) {
+                Tag::Other => (),
+                Tag::Start(name) => {
+                    if self.depth == 1 {
+                        self.child = Some(Child {
+                            name: String::from(name),
+                            start,
+                            content: end,
+                        });
+                    }
+                    self.depth += 1;
+                }
+                Tag::Empty(name) => {
+                    if self.depth == 1 {
+                        let name = String::from(name);
+                        if self.elements.contains(&name.as_str()) {
+                            return Ok(Some((name, String::from(tag))));
+                        }
+                        self.fields.insert(name, String::new());
+                    }
+                }
+                Tag::End => {
+                    self.depth = self
+                        .depth
+                        .checked_sub(1)
+                        .ok_or_else(|| invalid_data("unexpected XML end tag"))?;
+                    if self.depth != 1 {
+                        continue;
+                    }
+                    let child = match self.child.take() {
+                        Some(child) => child,
+                        None => continue,
+                    };
+                    if self.elements.contains(&child.name.as_str()) {
+                        let xml = String::from_utf8(self.buf[child.start..end].to_vec())
+                            .map_err(|_| invalid_data("XML document is not valid UTF-8"))?;
+                        return Ok(Some((child.name, xml)));
+                    }
+                    let text = std::str::from_utf8(&self.buf[child.content..start])
+                        .map_err(|_| invalid_data("XML document is not valid UTF-8"))?;
+                    self.fields.insert(child.name, unescape(text));
+                }
+            }
+        }
+    }
+
+    /// Drops all bytes from the buffer that are no longer needed.
+    fn compact(&mut self) {
+        let keep = match self.child {
+            Some(ref child) => child.start,
+            None => self.pos,
+        };
+        self.buf.drain(..keep);
+        self.pos -= keep;
+        if let Some(ref mut child) = self.child {
+            child.start -= keep;
+            child.content -= keep;
+        }
+    }
+}
+
+fn parse_tag(tag: &str) -> Tag<'_> {
+    if tag.starts_with("<?") || tag.starts_with("<!") {
+        return Tag::Other;
+    }
+    if tag.starts_with("</") {
+        return Tag::End;
+    }
+    let empty = tag.ends_with("/>");
+    let inner = tag
+        .trim_start_matches('<')
+        .trim_end_matches('>')
+        .trim_end_matches('/');
+    let name = inner
+        .split(|c: char| c.is_ascii_whitespace())
+        .next()
+        .unwrap_or_default();
+    match empty {
+        true => Tag::Empty(name),
+        false => Tag::Start(name),
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// Escapes the characters that must not appear in XML text.
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Replaces the predefined XML entities.
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+fn invalid_data(msg: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
@@ -0,0 +1,116 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::{accelerate::AccelerateStatus, Bucket, Client, Region};
+use std::time::Duration;
+
+#[test]
+fn accelerate_configuration() {
+    let server = Server::start(|request| {
+        match request.method.as_str() {
+        "GET" => Response::new(200).body(
+            r#"<AccelerateConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/"><Status>Suspended</Status></AccelerateConfiguration>"#,
+        ),
+        _ => Response::new(200),
+    }
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    task::block_on(bucket.put_accelerate_configuration(AccelerateStatus::Enabled)).unwrap();
+    let request = &server.requests()[0];
+    assert_eq!("PUT", request.method);
+    assert_eq!(Some("accelerate="), request.query());
+    assert!(String::from_utf8(request.body.clone())
+        .unwrap()
+        .contains("<Status>Enabled</Status>"));
+
+    let status = task::block_on(bucket.get_accelerate_configuration()).unwrap();
+    assert_eq!(Some(AccelerateStatus::Suspended), status);
+}
+
+#[test]
+fn accelerate_not_configured() {
+    let server = Server::start(|_| {
+        Response::new(200)
+            .body(r#"<AccelerateConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/"/>"#)
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+    assert_eq!(
+        None,
+        task::block_on(bucket.get_accelerate_configuration()).unwrap()
+    );
+}
+
+#[test]
+fn accelerated_urls() {
+    let bucket = Bucket::new("my-bucket", Region::UsWest2, common::credentials())
+        .unwrap()
+        .with_acceleration(true);
+    assert!(bucket.client().acceleration());
+
+    let url =
+        task::block_on(bucket.presign_get("dir/data.bin", Duration::from_secs(3600))).unwrap();
+    assert!(
+        url.as_str()
+            .starts_with("https://my-bucket.s3-accelerate.amazonaws.com/dir/data.bin?"),
+        "{}",
+        url
+    );
+
+    // Acceleration is not available for names with periods
+    // and outside the AWS commercial regions.
+    let bucket = Bucket::new("www.example.com", Region::UsWest2, common::credentials())
+        .unwrap()
+        .with_acceleration(true);
+    let url = task::block_on(bucket.presign_get("data.bin", Duration::from_secs(3600))).unwrap();
+    assert!(url
+        .as_str()
+        .starts_with("https://s3-us-west-2.amazonaws.com/www.example.com/data.bin?"));
+
+    let client = Client::new(Region::CnNorth1, common::credentials()).with_acceleration(true);
+    let url = task::block_on(
+        client
+            .bucket("my-bucket")
+            .unwrap()
+            .presign_get("data.bin", Duration::from_secs(3600)),
+    )
+    .unwrap();
+    assert!(url
+        .as_str()
+        .starts_with("https://s3.cn-north-1.amazonaws.com.cn/my-bucket/data.bin?"));
+}
+
+#[test]
+fn accelerated_requests() {
+    let recorder = std::sync::Arc::new(common::Recorder::default());
+    let client = Client::new(Region::UsWest2, common::credentials())
+        .with_transport(recorder.clone())
+        .with_acceleration(true);
+    let bucket = client.bucket("my-bucket").unwrap();
+
+    task::block_on(bucket.put_object_bytes("data.bin", b"data")).unwrap();
+    task::block_on(bucket.exists()).unwrap();
+
+    let urls = recorder.urls.lock().unwrap();
+    assert_eq!(
+        "https://my-bucket.s3-accelerate.amazonaws.com/data.bin",
+        urls[0]
+    );
+    assert_eq!("https://s3-us-west-2.amazonaws.com/my-bucket/", urls[1]);
+}
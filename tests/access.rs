@@ -0,0 +1,68 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::{access::PublicAccessBlock, Bucket};
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn public_access_block() {
+    let stored = Arc::new(Mutex::new(Vec::new()));
+    let server = Server::start({
+        let stored = stored.clone();
+        move |request| match request.method.as_str() {
+            "PUT" => {
+                *stored.lock().unwrap() = request.body.clone();
+                Response::new(200)
+            }
+            "DELETE" => Response::new(204),
+            _ => Response::new(200).body(stored.lock().unwrap().clone()),
+        }
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let config = PublicAccessBlock::new()
+        .with_block_public_acls(true)
+        .with_restrict_public_buckets(true);
+    task::block_on(bucket.put_public_access_block(&config)).unwrap();
+    let requests = server.requests();
+    assert_eq!(Some("publicAccessBlock="), requests[0].query());
+    let body = String::from_utf8(requests[0].body.clone()).unwrap();
+    assert!(body.contains("<BlockPublicAcls>true</BlockPublicAcls>"));
+    assert!(body.contains("<IgnorePublicAcls>false</IgnorePublicAcls>"));
+
+    let stored = task::block_on(bucket.get_public_access_block()).unwrap();
+    assert_eq!(config, stored);
+    assert!(!stored.block_public_policy());
+
+    task::block_on(bucket.delete_public_access_block()).unwrap();
+    assert_eq!("DELETE", server.requests()[2].method);
+}
+
+#[test]
+fn missing_public_access_block() {
+    let server = Server::start(|_| {
+        Response::new(404).body(
+            "<Error><Code>NoSuchPublicAccessBlockConfiguration</Code>\
+             <Message>The public access block configuration was not found</Message></Error>",
+        )
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    assert!(task::block_on(bucket.get_public_access_block()).is_err());
+}
@@ -0,0 +1,130 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::{acl::*, Bucket};
+
+const OWNER: &str = "75aa57f09aa0c8caeab4f8c24e99d10f8e7faeebf76c078efc7c6caea54ba06a";
+
+fn policy() -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<AccessControlPolicy xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+  <Owner><ID>{owner}</ID><DisplayName>mtd@amazon.com</DisplayName></Owner>
+  <AccessControlList>
+    <Grant>
+      <Grantee xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:type="CanonicalUser">
+        <ID>{owner}</ID><DisplayName>mtd@amazon.com</DisplayName>
+      </Grantee>
+      <Permission>FULL_CONTROL</Permission>
+    </Grant>
+    <Grant>
+      <Grantee xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:type="Group">
+        <URI>http://acs.amazonaws.com/groups/global/AllUsers</URI>
+      </Grantee>
+      <Permission>READ</Permission>
+    </Grant>
+  </AccessControlList>
+</AccessControlPolicy>"#,
+        owner = OWNER
+    )
+}
+
+#[test]
+fn bucket_acl() {
+    let server = Server::start(|request| match request.method.as_str() {
+        "GET" => Response::new(200).body(policy()),
+        _ => Response::new(200),
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let acl = task::block_on(bucket.get_acl()).unwrap();
+    assert_eq!(OWNER, acl.owner().id());
+    assert_eq!(Some("mtd@amazon.com"), acl.owner().display_name());
+    let expected = AccessControlList::new(Owner::new(OWNER))
+        .with_grant(
+            Grantee::CanonicalUser(String::from(OWNER)),
+            Permission::FullControl,
+        )
+        .with_grant(Grantee::Group(Group::AllUsers), Permission::Read);
+    assert_eq!(expected.grants(), acl.grants());
+
+    let acl = expected.with_grant(
+        Grantee::Email(String::from("a@b.com")),
+        Permission::WriteAcp,
+    );
+    task::block_on(bucket.put_acl(&acl)).unwrap();
+    let request = &server.requests()[1];
+    assert_eq!(Some("acl="), request.query());
+    let body = String::from_utf8(request.body.clone()).unwrap();
+    assert!(body.contains(
+        r#"xsi:type="Group"><URI>http://acs.amazonaws.com/groups/global/AllUsers</URI></Grantee><Permission>READ</Permission>"#
+    ));
+    assert!(body.contains(
+        r#"xsi:type="AmazonCustomerByEmail"><EmailAddress>a@b.com</EmailAddress></Grantee><Permission>WRITE_ACP</Permission>"#
+    ));
+
+    task::block_on(bucket.put_canned_acl(CannedAcl::PublicRead)).unwrap();
+    let request = &server.requests()[2];
+    assert_eq!(Some("public-read"), request.header("x-amz-acl"));
+    assert!(request.body.is_empty());
+}
+
+#[test]
+fn object_acl() {
+    let server = Server::start(|request| match request.method.as_str() {
+        "GET" => Response::new(200).body(policy()),
+        _ => Response::new(200),
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let acl = task::block_on(bucket.get_object_acl("report.pdf")).unwrap();
+    assert_eq!(2, acl.grants().len());
+    assert_eq!("/my-bucket/report.pdf", server.requests()[0].path());
+
+    task::block_on(bucket.put_object_acl("report.pdf", &acl)).unwrap();
+    assert!(server.requests()[1].header("content-md5").is_some());
+
+    task::block_on(bucket.put_object_canned_acl("report.pdf", CannedAcl::BucketOwnerFullControl))
+        .unwrap();
+    assert_eq!(
+        Some("bucket-owner-full-control"),
+        server.requests()[2].header("x-amz-acl")
+    );
+}
+
+#[test]
+fn canned_acl() {
+    for acl in [
+        CannedAcl::Private,
+        CannedAcl::PublicRead,
+        CannedAcl::PublicReadWrite,
+        CannedAcl::AuthenticatedRead,
+        CannedAcl::AwsExecRead,
+        CannedAcl::BucketOwnerRead,
+        CannedAcl::BucketOwnerFullControl,
+        CannedAcl::LogDeliveryWrite,
+    ] {
+        assert_eq!(acl, acl.to_string().parse().unwrap());
+    }
+    assert!("public".parse::<CannedAcl>().is_err());
+    assert_eq!(
+        Group::Other(String::from("urn:group")),
+        Group::from_uri("urn:group")
+    );
+}
@@ -0,0 +1,100 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::{AddressingStyle, Bucket, Region};
+
+#[test]
+fn auto_addressing_style() {
+    let style = AddressingStyle::Auto;
+    assert!(style.is_virtual_hosted(&Region::UsEast1, "my-bucket"));
+    assert!(style.is_virtual_hosted(&Region::CnNorth1, "my-bucket"));
+
+    // Periods do not match the wildcard TLS certificate.
+    assert!(!style.is_virtual_hosted(&Region::UsEast1, "www.example.com"));
+    // Names that are no valid DNS labels.
+    assert!(!style.is_virtual_hosted(&Region::UsEast1, "My_Bucket"));
+
+    let region =
+        Region::custom_with_region("https://s3.eu-central-1.amazonaws.com", "eu-central-1")
+            .unwrap();
+    assert!(style.is_virtual_hosted(&region, "my-bucket"));
+
+    for endpoint in [
+        "https://minio.example.com",
+        "https://s3.amazonaws.com:8443",
+        "http://192.168.1.10",
+        "http://[::1]",
+        "localhost:9000",
+    ] {
+        let region = Region::custom(endpoint).unwrap();
+        assert!(
+            !style.is_virtual_hosted(&region, "my-bucket"),
+            "{}",
+            endpoint
+        );
+    }
+
+    assert!(!AddressingStyle::Path.is_virtual_hosted(&Region::UsEast1, "my-bucket"));
+    let region = Region::custom("http://192.168.1.10").unwrap();
+    assert!(AddressingStyle::VirtualHosted.is_virtual_hosted(&region, "my-bucket"));
+}
+
+#[test]
+fn virtual_hosted_requests() {
+    let server = Server::start(|_| {
+        Response::new(200)
+            .header("ETag", "\"8d777f385d3dfec8815d20f7496026dc\"")
+            .body("data")
+    });
+    let endpoint = server.endpoint().replace("127.0.0.1", "localhost");
+    let region = Region::custom_with_region(&endpoint, "us-east-1").unwrap();
+    let bucket = Bucket::new("my-bucket", region, common::credentials())
+        .unwrap()
+        .with_addressing_style(AddressingStyle::VirtualHosted);
+
+    task::block_on(bucket.get_object("dir/data.bin")).unwrap();
+    assert!(task::block_on(bucket.exists()).unwrap());
+    task::block_on(bucket.list_objects("dir/", None)).ok();
+
+    let requests = server.requests();
+    let host = format!("my-bucket.{}", &endpoint["http://".len()..]);
+    assert_eq!("/dir/data.bin", requests[0].path());
+    assert_eq!(Some(host.as_str()), requests[0].header("host"));
+    assert_eq!("/", requests[1].path());
+    assert_eq!("/", requests[2].path());
+    assert!(requests[2].query().unwrap().contains("list-type=2"));
+}
+
+#[test]
+fn virtual_hosted_failover() {
+    let server = Server::start(|_| Response::new(200));
+    let endpoint = server.endpoint().replace("127.0.0.1", "localhost");
+    let region =
+        Region::custom_with_endpoints(["http://localhost:1", endpoint.as_str()], "us-east-1")
+            .unwrap();
+    let bucket = Bucket::new("my-bucket", region, common::credentials())
+        .unwrap()
+        .with_addressing_style(AddressingStyle::VirtualHosted);
+
+    assert!(task::block_on(bucket.exists()).unwrap());
+    let request = &server.requests()[0];
+    let host = format!("my-bucket.{}", &endpoint["http://".len()..]);
+    assert_eq!(Some(host.as_str()), request.header("host"));
+    assert_eq!("/", request.path());
+}
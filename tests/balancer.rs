@@ -0,0 +1,63 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::{balancer::Strategy, Balancer, Bucket, Region};
+
+fn object(_: &common::Request) -> Response {
+    Response::new(200)
+        .header("ETag", "\"b10a8db164e0754105b7a99be72e3fe5\"")
+        .body("Hello World")
+}
+
+#[test]
+fn failover() {
+    let server = Server::start(object);
+    let region =
+        Region::custom_with_endpoints(["http://127.0.0.1:1", server.endpoint()], "us-east-1")
+            .unwrap();
+    let bucket = Bucket::new("my-bucket", region, common::credentials()).unwrap();
+
+    for _ in 0..2 {
+        let object = task::block_on(bucket.get_object("object")).unwrap();
+        assert_eq!(11, object.metadata().size());
+    }
+    assert_eq!(2, server.requests().len());
+}
+
+#[test]
+fn round_robin() {
+    let a = Server::start(object);
+    let b = Server::start(object);
+    let region = Region::custom_with_endpoints([a.endpoint(), b.endpoint()], "us-east-1").unwrap();
+    let bucket = Bucket::new("my-bucket", region, common::credentials())
+        .unwrap()
+        .with_balancer(Balancer::new(Strategy::RoundRobin));
+
+    for _ in 0..4 {
+        task::block_on(bucket.get_object("object")).unwrap();
+    }
+    assert_eq!(2, a.requests().len());
+    assert_eq!(2, b.requests().len());
+
+    let host = b.endpoint().trim_start_matches("http://");
+    for request in b.requests() {
+        assert_eq!(Some(host), request.header("host"));
+        assert_eq!("/my-bucket/object", request.path());
+    }
+}
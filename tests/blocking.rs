@@ -0,0 +1,86 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use common::{Response, Server};
+use minio::s3::{blocking, Bucket};
+use std::io::{Cursor, Read};
+
+const ETAG: &str = "\"b10a8db164e0754105b7a99be72e3fe5\"";
+
+#[test]
+fn put_and_get_object() {
+    let server = Server::start(|request| match request.method.as_str() {
+        "GET" => Response::new(200).header("ETag", ETAG).body("Hello World"),
+        "DELETE" => Response::new(204),
+        _ => Response::new(200).header("ETag", ETAG),
+    });
+    let bucket =
+        blocking::Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    bucket
+        .put_object_bytes("hello.txt", b"Hello World")
+        .unwrap();
+    let request = &server.requests()[0];
+    assert_eq!("PUT", request.method);
+    assert_eq!("/my-bucket/hello.txt", request.path());
+    assert_eq!(b"Hello World".as_slice(), request.body.as_slice());
+
+    let mut object = bucket.get_object("hello.txt").unwrap();
+    assert_eq!("hello.txt", object.name());
+    let mut content = String::new();
+    object.read_to_string(&mut content).unwrap();
+    assert_eq!("Hello World", content);
+
+    bucket.delete_object("hello.txt").unwrap();
+    assert_eq!("DELETE", server.requests()[2].method);
+}
+
+#[test]
+fn put_object_streamed() {
+    let server = Server::start(|_| Response::new(200).header("ETag", ETAG));
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+    let bucket = blocking::Bucket::from(bucket);
+
+    bucket
+        .put_object_streamed("hello.txt", Cursor::new(b"Hello World"))
+        .unwrap();
+    let request = &server.requests()[0];
+    assert_eq!("PUT", request.method);
+    assert_eq!(b"Hello World".as_slice(), request.body.as_slice());
+}
+
+#[test]
+fn stat_object_not_found() {
+    let server = Server::start(|_| Response::new(404));
+    let bucket =
+        blocking::Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let err = bucket.stat_object("missing.txt").unwrap_err();
+    assert!(err.is_not_found());
+}
+
+#[test]
+fn delete_bucket() {
+    let server = Server::start(|_| Response::new(204));
+    let bucket =
+        blocking::Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    bucket.delete().unwrap();
+    let request = &server.requests()[0];
+    assert_eq!("DELETE", request.method);
+    assert_eq!("/my-bucket/", request.path());
+}
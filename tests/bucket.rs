@@ -1,4 +1,7 @@
+mod common;
+
 use async_std::task;
+use common::{Response, Server};
 use minio::s3;
 
 #[test]
@@ -17,10 +20,10 @@ fn create_bucket() {
         credentials.clone(),
         s3::bucket::Configuration::default(),
     ));
-    let bucket = match result {
+    let _bucket = match result {
         Ok(bucket) => bucket,
         Err(err) if Some(s3::ErrorCode::BucketAlreadyOwnedByYou) == err.code() => {
-            s3::Bucket::new(name, region, credentials)
+            s3::Bucket::new(name, region, credentials).unwrap()
         }
         Err(err) => panic!("{}", err),
     };
@@ -35,7 +38,7 @@ fn get_object() {
         "Q3AM3UQ867SPQQA43P2F",
         "zuf+tfteSlswRu7BJ86wekitnifILbZam1KYY3TG",
     );
-    let bucket = s3::Bucket::new("my-bucket", region, credentials);
+    let bucket = s3::Bucket::new("my-bucket", region, credentials).unwrap();
     let mut object = task::block_on(bucket.get_object("test.file")).unwrap();
 
     task::block_on(async_std::io::copy(
@@ -49,3 +52,29 @@ fn get_object() {
         object.metadata().storage_class()
     );
 }
+
+#[test]
+fn bucket_exists() {
+    let server = Server::start(|request| match request.path().trim_end_matches('/') {
+        "/my-bucket" => Response::new(200),
+        "/private-bucket" => Response::new(403),
+        _ => Response::new(404),
+    });
+
+    let bucket = s3::Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+    assert!(task::block_on(bucket.exists()).unwrap());
+    assert_eq!("HEAD", server.requests()[0].method);
+
+    let bucket = s3::Bucket::new("other-bucket", server.region(), common::credentials()).unwrap();
+    assert!(!task::block_on(bucket.exists()).unwrap());
+
+    let bucket = s3::Bucket::new("private-bucket", server.region(), common::credentials()).unwrap();
+    let err = task::block_on(bucket.exists()).unwrap_err();
+    assert_eq!(Some(s3::ErrorCode::AccessDenied), err.code());
+
+    // Transport errors are not reported as missing bucket.
+    let region = s3::Region::custom_with_region("http://127.0.0.1:1", "us-east-1").unwrap();
+    let bucket = s3::Bucket::new("my-bucket", region, common::credentials()).unwrap();
+    let err = task::block_on(bucket.exists()).unwrap_err();
+    assert_eq!(None, err.code());
+}
@@ -0,0 +1,169 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::{Bucket, CancellationToken, Result};
+use std::{
+    future::Future,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+    time::{Duration, Instant},
+};
+
+/// A waker that does nothing.
+struct Noop;
+
+impl Wake for Noop {
+    fn wake(self: Arc<Self>) {}
+}
+
+#[test]
+fn cancel() {
+    let token = CancellationToken::new();
+    assert!(!token.is_cancelled());
+
+    // Cancelling a clone wakes up futures waiting for the token.
+    let clone = token.clone();
+    task::spawn(async move {
+        task::sleep(Duration::from_millis(50)).await;
+        clone.cancel();
+    });
+    let start = Instant::now();
+    let result: Result<()> = task::block_on(token.run(async {
+        task::sleep(Duration::from_secs(10)).await;
+        Ok(())
+    }));
+    assert!(result.unwrap_err().is_cancelled());
+    assert!(start.elapsed() < Duration::from_secs(10));
+    assert!(token.is_cancelled());
+}
+
+#[test]
+fn cancel_deadline() {
+    let token = CancellationToken::with_timeout(Duration::from_millis(50));
+    assert!(!token.is_cancelled());
+
+    let result: Result<()> = task::block_on(token.run(async {
+        task::sleep(Duration::from_secs(10)).await;
+        Ok(())
+    }));
+    assert!(result.unwrap_err().is_cancelled());
+    assert!(token.is_cancelled());
+
+    // Futures that complete in time are not affected.
+    let token = CancellationToken::with_timeout(Duration::from_secs(10));
+    assert_eq!(1, task::block_on(token.run(async { Ok(1) })).unwrap());
+}
+
+#[test]
+fn cancel_multipart_upload() {
+    let server = Server::start(|request| {
+        match request.method.as_str() {
+        "POST" => Response::new(200).body(
+            "<InitiateMultipartUploadResult><UploadId>upload-id</UploadId></InitiateMultipartUploadResult>",
+        ),
+        "DELETE" => Response::new(204),
+        _ => Response::new(200).header("ETag", "\"b54357faf0632cce46e942fa68356b38\""),
+    }
+    });
+    let token = CancellationToken::new();
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials())
+        .unwrap()
+        .with_cancellation(token.clone());
+
+    let result = task::block_on(bucket.put_object_multipart("large.bin", |upload| {
+        let bucket = &bucket;
+        let token = &token;
+        async move {
+            let first = bucket.upload_part(&upload, 1, "first").await?;
+            token.cancel();
+            let second = bucket.upload_part(&upload, 2, "second").await?;
+            Ok(vec![first, second])
+        }
+    }));
+    assert!(result.unwrap_err().is_cancelled());
+
+    // The upload has been aborted although the bucket's
+    // requests have been cancelled.
+    let requests = server.requests();
+    let methods: Vec<&str> = requests.iter().map(|r| r.method.as_str()).collect();
+    assert_eq!(vec!["POST", "PUT", "DELETE"], methods);
+    assert_eq!(Some("uploadId=upload-id"), requests[2].query());
+
+    let err = task::block_on(bucket.put_object_bytes("small.txt", "data")).unwrap_err();
+    assert!(err.is_cancelled());
+    assert_eq!(3, server.requests().len());
+}
+
+#[test]
+fn cancel_dropped_multipart_upload() {
+    let server = Server::start(|request| {
+        match request.method.as_str() {
+        "POST" => Response::new(200).body(
+            "<InitiateMultipartUploadResult><UploadId>upload-id</UploadId></InitiateMultipartUploadResult>",
+        ),
+        "DELETE" => Response::new(204),
+        _ => Response::new(200).header("ETag", "\"b54357faf0632cce46e942fa68356b38\""),
+    }
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+    let token = CancellationToken::new();
+
+    // The token only drops the upload future, without
+    // cancelling the bucket's requests.
+    let upload = bucket.put_object_multipart("large.bin", |upload| {
+        let (bucket, token) = (&bucket, &token);
+        async move {
+            let first = bucket.upload_part(&upload, 1, "first").await?;
+            token.cancel();
+            task::sleep(Duration::from_secs(10)).await;
+            Ok(vec![first])
+        }
+    });
+    let result = task::block_on(token.run(upload));
+    assert!(result.unwrap_err().is_cancelled());
+
+    // The upload is aborted in the background.
+    let start = Instant::now();
+    while server.requests().len() < 3 && start.elapsed() < Duration::from_secs(5) {
+        task::block_on(task::sleep(Duration::from_millis(10)));
+    }
+    let requests = server.requests();
+    let methods: Vec<&str> = requests.iter().map(|r| r.method.as_str()).collect();
+    assert_eq!(vec!["POST", "PUT", "DELETE"], methods);
+    assert_eq!(Some("uploadId=upload-id"), requests[2].query());
+}
+
+#[test]
+fn waiting_futures_release_wakers() {
+    let token = CancellationToken::new();
+    let noop = Arc::new(Noop);
+    let waker = Waker::from(noop.clone());
+    let mut cx = Context::from_waker(&waker);
+
+    for _ in 0..100 {
+        let mut cancelled = Box::pin(token.cancelled());
+        // Polling again replaces the registered waker.
+        for _ in 0..10 {
+            assert_eq!(Poll::Pending, cancelled.as_mut().poll(&mut cx));
+        }
+        assert!(Arc::strong_count(&noop) <= 3);
+    }
+    // Dropped futures no longer hold a waker.
+    assert_eq!(2, Arc::strong_count(&noop));
+}
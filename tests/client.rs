@@ -0,0 +1,104 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::{request::Method, Client, ErrorCode};
+
+#[test]
+fn ping() {
+    let server = Server::start(|_| Response::new(200).body("<ListAllMyBucketsResult/>"));
+    let client = Client::new(server.region(), common::credentials());
+
+    task::block_on(client.ping()).unwrap();
+
+    let requests = server.requests();
+    assert_eq!(1, requests.len());
+    assert_eq!("GET", requests[0].method);
+    assert_eq!("/", requests[0].path());
+    assert_eq!(Some("max-buckets=1"), requests[0].query());
+    assert!(requests[0].header("authorization").is_some());
+}
+
+#[test]
+fn ping_access_denied() {
+    let server = Server::start(|_| Response::error(403, "AccessDenied"));
+    let client = Client::new(server.region(), common::credentials());
+
+    let err = task::block_on(client.ping()).unwrap_err();
+    assert_eq!(Some(ErrorCode::AccessDenied), err.code());
+}
+
+#[test]
+fn list_buckets() {
+    let server = Server::start(|request| {
+        let body = match request.query() {
+            None => "<ListAllMyBucketsResult><Owner><ID>owner</ID></Owner><Buckets>\
+                 <Bucket><Name>logs</Name><CreationDate>2022-03-01T10:00:00.000Z</CreationDate></Bucket>\
+                 <Bucket><Name>photos</Name><CreationDate>2022-03-02T10:00:00.000Z</CreationDate></Bucket>\
+                 </Buckets><ContinuationToken>next</ContinuationToken></ListAllMyBucketsResult>",
+            _ => "<ListAllMyBucketsResult><Buckets>\
+                 <Bucket><Name>videos</Name><CreationDate>2022-03-03T10:00:00.000Z</CreationDate></Bucket>\
+                 </Buckets></ListAllMyBucketsResult>",
+        };
+        Response::new(200).body(body)
+    });
+    let client = Client::new(server.region(), common::credentials());
+
+    let buckets = task::block_on(client.list_buckets()).unwrap();
+    let names: Vec<&str> = buckets
+        .iter()
+        .map(|bucket| bucket.name().as_str())
+        .collect();
+    assert_eq!(vec!["logs", "photos", "videos"], names);
+    assert_eq!(2022, buckets[0].creation_date().year());
+
+    let requests = server.requests();
+    assert_eq!("/", requests[0].path());
+    assert_eq!(Some("continuation-token=next"), requests[1].query());
+}
+
+#[test]
+fn execute() {
+    let server = Server::start(|request| match request.query() {
+        Some("versioning=") => Response::new(200).body("<VersioningConfiguration/>"),
+        _ => Response::error(403, "AccessDenied"),
+    });
+    let client = Client::new(server.region(), common::credentials());
+
+    let request = client
+        .request(Method::Get, "/my-bucket")
+        .unwrap()
+        .query("versioning", "")
+        .sign_empty(client.credentials())
+        .unwrap();
+    let mut response = task::block_on(client.execute(request)).unwrap();
+    let body = task::block_on(response.body_string()).unwrap();
+    assert_eq!("<VersioningConfiguration/>", body);
+
+    let request = client
+        .request(Method::Get, "my-bucket")
+        .unwrap()
+        .sign_empty(client.credentials())
+        .unwrap();
+    let err = task::block_on(client.execute(request)).unwrap_err();
+    assert_eq!(Some(ErrorCode::AccessDenied), err.code());
+
+    let requests = server.requests();
+    assert_eq!("/my-bucket", requests[0].path());
+    assert!(requests[0].header("authorization").is_some());
+}
@@ -0,0 +1,107 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::{convert, request::Method, sv4, Client};
+
+#[test]
+fn request_round_trip() {
+    let server = Server::start(|_| Response::new(200).header("ETag", "\"abc\"").body("done"));
+    let client = Client::new(server.region(), common::credentials());
+
+    let request = client
+        .request(Method::Put, "my-bucket/object")
+        .unwrap()
+        .query("tagging", "")
+        .header("X-Amz-Meta-Color", "blue")
+        .sign_bytes(client.credentials(), "data")
+        .unwrap();
+    let signature = sv4::debug_sign(client.region(), client.credentials(), &request).unwrap();
+
+    let request = task::block_on(convert::into_http_request(request)).unwrap();
+    assert_eq!(http::Method::PUT, request.method());
+    assert_eq!(Some("tagging="), request.uri().query());
+    assert_eq!("blue", request.headers()["x-amz-meta-color"]);
+    assert_eq!(b"data", request.body().as_slice());
+
+    // The signature is not affected by the conversion.
+    let request = convert::from_http_request(request).unwrap();
+    assert_eq!(
+        signature,
+        sv4::debug_sign(client.region(), client.credentials(), &request).unwrap()
+    );
+
+    let response = task::block_on(client.execute(request)).unwrap();
+    let response = task::block_on(convert::into_http_response(response)).unwrap();
+    assert_eq!(http::StatusCode::OK, response.status());
+    assert_eq!("\"abc\"", response.headers()["etag"]);
+    assert_eq!(b"done", response.body().as_slice());
+    assert_eq!(b"data", server.requests()[0].body.as_slice());
+}
+
+#[test]
+fn response_from_http() {
+    let response = http::Response::builder()
+        .status(404)
+        .header("Content-Type", "application/xml")
+        .body("<Error/>")
+        .unwrap();
+
+    let mut response = convert::from_http_response(response).unwrap();
+    assert_eq!(404, u16::from(response.status()));
+    assert_eq!(
+        Some("application/xml"),
+        response.header("content-type").map(|v| v.as_str())
+    );
+    assert_eq!("<Error/>", task::block_on(response.body_string()).unwrap());
+}
+
+#[test]
+fn headers_are_kept() {
+    let request = http::Request::builder()
+        .method("GET")
+        .uri("https://s3.amazonaws.com/my-bucket/object")
+        .header("X-Amz-Date", "20130524T000000Z")
+        .body(Vec::new())
+        .unwrap();
+    let request = convert::from_http_request(request).unwrap();
+    assert!(request.header("content-type").is_none());
+    assert_eq!(
+        vec!["x-amz-date"],
+        request
+            .header_names()
+            .map(|n| n.as_str())
+            .collect::<Vec<_>>()
+    );
+
+    let request = http::Request::builder()
+        .method("PUT")
+        .uri("https://s3.amazonaws.com/my-bucket/object")
+        .header("Content-Type", "text/plain")
+        .body("Hello World")
+        .unwrap();
+    let request = convert::from_http_request(request).unwrap();
+    assert_eq!(
+        Some("text/plain"),
+        request.header("content-type").map(|v| v.as_str())
+    );
+
+    let response = http::Response::builder().status(204).body("").unwrap();
+    let response = convert::from_http_response(response).unwrap();
+    assert!(response.header("content-type").is_none());
+}
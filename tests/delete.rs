@@ -0,0 +1,117 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::{Bucket, ErrorCode};
+
+#[test]
+fn delete_object() {
+    let server = Server::start(|_| {
+        Response::new(204)
+            .header("x-amz-delete-marker", "true")
+            .header("x-amz-version-id", "3HL4kqtJlcpXroDTDmJ+rmSpXd3dIbrHY")
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let result = task::block_on(bucket.delete_object("photos/cat.jpg")).unwrap();
+    assert!(result.is_delete_marker());
+    assert_eq!(
+        Some("3HL4kqtJlcpXroDTDmJ+rmSpXd3dIbrHY"),
+        result.version_id()
+    );
+
+    let requests = server.requests();
+    assert_eq!("DELETE", requests[0].method);
+    assert_eq!("/my-bucket/photos/cat.jpg", requests[0].path());
+
+    // Unversioned buckets return neither header.
+    let server = Server::start(|_| Response::new(204));
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+    let result = task::block_on(bucket.delete_object("photos/cat.jpg")).unwrap();
+    assert!(!result.is_delete_marker());
+    assert_eq!(None, result.version_id());
+}
+
+#[test]
+fn delete_object_error() {
+    let server = Server::start(|_| {
+        Response::new(404).body(
+            "<Error><Code>NoSuchBucket</Code>\
+             <Message>The specified bucket does not exist</Message></Error>",
+        )
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let err = task::block_on(bucket.delete_object("photos/cat.jpg")).unwrap_err();
+    assert_eq!(Some(ErrorCode::NoSuchBucket), err.code());
+}
+
+#[test]
+fn delete_objects() {
+    let server = Server::start(|request| {
+        let body = String::from_utf8(request.body.clone()).unwrap();
+        let count = body.matches("<Object>").count();
+        let mut result = String::from("<DeleteResult>");
+        if count == 1 {
+            result.push_str(
+                "<Error><Key>logs/locked.log</Key><Code>AccessDenied</Code>\
+                 <Message>Access Denied</Message></Error>",
+            );
+        } else {
+            result.push_str(
+                "<Deleted><Key>logs/0.log</Key><DeleteMarker>true</DeleteMarker>\
+                 <DeleteMarkerVersionId>v1</DeleteMarkerVersionId></Deleted>",
+            );
+            for _ in 1..count {
+                result.push_str("<Deleted><Key>logs/n.log</Key></Deleted>");
+            }
+        }
+        result.push_str("</DeleteResult>");
+        Response::new(200).body(result)
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let keys: Vec<String> = (0..1000)
+        .map(|i| format!("logs/{}.log", i))
+        .chain([String::from("logs/locked.log")])
+        .collect();
+    let result = task::block_on(bucket.delete_objects(&keys)).unwrap();
+    assert!(!result.is_success());
+    assert_eq!(1000, result.deleted().len());
+    assert!(result.deleted()[0].is_delete_marker());
+    assert_eq!(Some("v1"), result.deleted()[0].version_id());
+    assert_eq!("logs/locked.log", result.errors()[0].key());
+    assert_eq!("AccessDenied", result.errors()[0].code());
+
+    let requests = server.requests();
+    assert_eq!(2, requests.len());
+    assert_eq!("POST", requests[0].method);
+    assert_eq!(Some("delete="), requests[0].query());
+    assert!(requests[0].header("content-md5").is_some());
+    let body = String::from_utf8(requests[1].body.clone()).unwrap();
+    assert!(body.contains("<Object><Key>logs/locked.log</Key></Object>"));
+}
+
+#[test]
+fn delete_objects_invalid_key() {
+    let server = Server::start(|_| Response::new(200));
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    assert!(task::block_on(bucket.delete_objects(["logs/a.log", ""])).is_err());
+    assert!(server.requests().is_empty());
+}
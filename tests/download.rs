@@ -0,0 +1,120 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::{download::DownloadOptions, Bucket, Etag};
+
+const DATA: &[u8] = b"0123456789";
+const ETAG: &str = "\"b10a8db164e0754105b7a99be72e3fe5\"";
+
+fn server() -> Server {
+    Server::start(|request| {
+        if request.path() == "/my-bucket/modified.bin" && request.method == "GET" {
+            return Response::error(412, "PreconditionFailed");
+        }
+        if request.method == "HEAD" {
+            return Response::new(200)
+                .header("ETag", ETAG)
+                .header("Content-Length", DATA.len());
+        }
+        let range = request
+            .header("range")
+            .unwrap()
+            .trim_start_matches("bytes=");
+        let (start, end) = range.split_once('-').unwrap();
+        let (start, end): (usize, usize) = (start.parse().unwrap(), end.parse().unwrap());
+        Response::new(206)
+            .header("ETag", ETAG)
+            .header(
+                "Content-Range",
+                format!("bytes {}-{}/{}", start, end, DATA.len()),
+            )
+            .body(&DATA[start..=end])
+    })
+}
+
+#[test]
+fn download_object() {
+    let server = server();
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let options = DownloadOptions::new().with_concurrency(2).with_part_size(3);
+    let mut data = Vec::new();
+    let metadata = task::block_on(bucket.download_object("data.bin", &mut data, &options)).unwrap();
+    assert_eq!(DATA, data.as_slice());
+    assert_eq!(10, metadata.size());
+
+    let requests = server.requests();
+    assert_eq!(5, requests.len());
+    let mut ranges: Vec<_> = requests[1..]
+        .iter()
+        .map(|request| request.header("range").unwrap())
+        .collect();
+    ranges.sort();
+    assert_eq!(
+        vec!["bytes=0-2", "bytes=3-5", "bytes=6-8", "bytes=9-9"],
+        ranges
+    );
+    assert!(requests[1..]
+        .iter()
+        .all(|request| request.header("if-match") == Some(ETAG)));
+}
+
+#[test]
+fn download_object_to_file() {
+    let server = server();
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+    let dir = std::env::temp_dir().join(format!("minio-download-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let options = DownloadOptions::new().with_part_size(4);
+    let path = dir.join("data.bin");
+    task::block_on(bucket.download_object_to_file("data.bin", &path, &options)).unwrap();
+    assert_eq!(DATA, std::fs::read(&path).unwrap().as_slice());
+
+    // A failed download does not leave a partial file behind.
+    let path = dir.join("modified.bin");
+    let err = task::block_on(bucket.download_object_to_file("modified.bin", &path, &options))
+        .unwrap_err();
+    assert!(err.code().is_some());
+    assert!(!path.exists());
+
+    // Nor does it replace an existing file.
+    std::fs::write(&path, b"old").unwrap();
+    task::block_on(bucket.download_object_to_file("modified.bin", &path, &options)).unwrap_err();
+    assert_eq!(b"old", std::fs::read(&path).unwrap().as_slice());
+    assert_eq!(2, std::fs::read_dir(&dir).unwrap().count());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn download_object_verify_etag() {
+    let server = server();
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    // The ETag of the test server is not the MD5 sum of `DATA`.
+    let options = DownloadOptions::new()
+        .with_part_size(4)
+        .with_verify_etag(true);
+    let mut data = Vec::new();
+    let err = task::block_on(bucket.download_object("data.bin", &mut data, &options)).unwrap_err();
+    let err = err.integrity().unwrap();
+    assert_eq!(ETAG.parse::<Etag>().unwrap(), *err.expected());
+    assert_eq!(Etag::compute_from(DATA), *err.actual());
+}
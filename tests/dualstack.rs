@@ -0,0 +1,70 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use minio::s3::{AddressingStyle, Client, Region};
+use std::sync::Arc;
+
+#[test]
+fn dual_stack_requests() {
+    let recorder = Arc::new(common::Recorder::default());
+    let client = Client::new(Region::UsWest2, common::credentials())
+        .with_transport(recorder.clone())
+        .with_dual_stack(true);
+    assert!(client.dual_stack());
+
+    let bucket = client.bucket("my-bucket").unwrap();
+    task::block_on(bucket.put_object_bytes("data.bin", b"data")).unwrap();
+
+    let bucket = client
+        .clone()
+        .with_addressing_style(AddressingStyle::Auto)
+        .bucket("my-bucket")
+        .unwrap();
+    task::block_on(bucket.put_object_bytes("data.bin", b"data")).unwrap();
+
+    let bucket = client
+        .clone()
+        .with_acceleration(true)
+        .bucket("my-bucket")
+        .unwrap();
+    task::block_on(bucket.put_object_bytes("data.bin", b"data")).unwrap();
+
+    let urls = recorder.urls.lock().unwrap();
+    assert_eq!(
+        "https://s3.dualstack.us-west-2.amazonaws.com/my-bucket/data.bin",
+        urls[0]
+    );
+    assert_eq!(
+        "https://my-bucket.s3.dualstack.us-west-2.amazonaws.com/data.bin",
+        urls[1]
+    );
+    assert_eq!(
+        "https://my-bucket.s3-accelerate.dualstack.amazonaws.com/data.bin",
+        urls[2]
+    );
+}
+
+#[test]
+fn dual_stack_custom_region() {
+    let region = Region::custom("http://localhost:9000").unwrap();
+    let client = Client::new(region, common::credentials()).with_dual_stack(true);
+    assert_eq!("http://localhost:9000", client.endpoint());
+
+    let client = Client::new(Region::UsWest2, common::credentials());
+    assert_eq!("https://s3-us-west-2.amazonaws.com", client.endpoint());
+}
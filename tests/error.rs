@@ -0,0 +1,167 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::{
+    download::DownloadOptions, Bucket, Error, ErrorCode, ErrorKind, InvalidObjectKey, ObjectKey,
+    Region,
+};
+use std::error::Error as _;
+use surf::StatusCode;
+
+const NO_SUCH_KEY: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+    <Error><Code>NoSuchKey</Code><Message>The specified key does not exist.</Message>\
+    <Resource>/my-bucket/a.txt</Resource><RequestId>4442587FB7D0A2F9</RequestId>\
+    <HostId>body-host-id</HostId></Error>";
+
+#[test]
+fn error_response() {
+    let server = Server::start(|_| {
+        Response::new(404)
+            .header("x-amz-request-id", "4442587FB7D0A2F9")
+            .header("x-amz-id-2", "header-host-id")
+            .body(NO_SUCH_KEY)
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let err = task::block_on(bucket.get_object("a.txt")).err().unwrap();
+    assert_eq!(Some(ErrorCode::NoSuchKey), err.code());
+    assert_eq!(Some(StatusCode::NotFound), err.status());
+    assert_eq!(Some("4442587FB7D0A2F9"), err.request_id());
+    assert_eq!(Some("header-host-id"), err.host_id());
+    assert_eq!(Some("/my-bucket/a.txt"), err.resource());
+    assert_eq!(Some(NO_SUCH_KEY), err.body());
+}
+
+#[test]
+fn request_ids_in_body() {
+    let server = Server::start(|_| Response::new(404).body(NO_SUCH_KEY));
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let err = task::block_on(bucket.get_object("a.txt")).err().unwrap();
+    assert_eq!(Some("4442587FB7D0A2F9"), err.request_id());
+    assert_eq!(Some("body-host-id"), err.host_id());
+}
+
+#[test]
+fn responses_without_body() {
+    let server = Server::start(|_| Response::new(403).header("x-amz-request-id", "A1B2C3"));
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let err = task::block_on(bucket.stat_object("a.txt")).unwrap_err();
+    assert_eq!(Some(ErrorCode::AccessDenied), err.code());
+    assert_eq!(Some(StatusCode::Forbidden), err.status());
+    assert_eq!(Some("A1B2C3"), err.request_id());
+    assert_eq!(None, err.resource());
+    assert_eq!(None, err.body());
+}
+
+#[test]
+fn unparsable_body() {
+    let html = "<html><body>502 Bad Gateway</body></html>";
+    let server = Server::start(move |_| Response::new(502).body(html));
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let err = task::block_on(bucket.get_object("a.txt")).err().unwrap();
+    assert_eq!(Some(ErrorCode::Undefined), err.code());
+    assert_eq!(Some(StatusCode::BadGateway), err.status());
+    assert_eq!(Some(html), err.body());
+    assert_eq!(None, err.request_id());
+}
+
+#[test]
+fn errors_without_response() {
+    let err = Error::from("".parse::<ObjectKey>().unwrap_err());
+    assert_eq!(None, err.status());
+    assert_eq!(None, err.body());
+}
+
+#[test]
+fn retryable_errors() {
+    let server = Server::start(|request| match request.path() {
+        "/my-bucket/slow" => Response::error(503, "SlowDown"),
+        "/my-bucket/timeout" => Response::error(400, "RequestTimeout"),
+        "/my-bucket/throttled" => Response::new(429),
+        "/my-bucket/denied" => Response::error(403, "AccessDenied"),
+        _ => Response::error(404, "NoSuchKey"),
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+    let get = |key| task::block_on(bucket.get_object(key)).err().unwrap();
+
+    assert_eq!(Some(ErrorCode::SlowDown), get("slow").code());
+    assert!(get("slow").is_retryable());
+    assert_eq!(Some(ErrorCode::RequestTimeout), get("timeout").code());
+    assert!(get("timeout").is_retryable());
+    assert!(get("throttled").is_retryable());
+    assert!(!get("denied").is_retryable());
+    assert!(!get("missing").is_retryable());
+    assert!(!Error::from("".parse::<ObjectKey>().unwrap_err()).is_retryable());
+}
+
+#[test]
+fn error_kinds_and_sources() {
+    let err = Error::from("".parse::<ObjectKey>().unwrap_err());
+    assert!(matches!(err.kind(), ErrorKind::Key(_)));
+    let source = err.source().expect("source of invalid key");
+    assert!(source.downcast_ref::<InvalidObjectKey>().is_some());
+
+    let server = Server::start(|_| Response::error(404, "NoSuchKey"));
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+    let err = task::block_on(bucket.get_object("a.txt")).err().unwrap();
+    assert!(matches!(err.kind(), ErrorKind::S3(ErrorCode::NoSuchKey, _)));
+    assert!(err.source().is_none());
+}
+
+#[test]
+fn transport_error_sources() {
+    // Nothing listens on the discard port.
+    let region = Region::custom_with_region("http://127.0.0.1:9", "us-east-1").unwrap();
+    let bucket = Bucket::new("my-bucket", region, common::credentials()).unwrap();
+
+    let err = task::block_on(bucket.get_object("a.txt")).err().unwrap();
+    assert!(matches!(err.kind(), ErrorKind::Http(_)));
+    let source = err.source().expect("source of transport error");
+    assert!(!source.to_string().is_empty());
+
+    // Chain reporters do not print the message twice.
+    assert_eq!("S3 transport error", err.to_string());
+    assert_ne!(err.to_string(), source.to_string());
+}
+
+#[test]
+fn local_errors_are_permanent() {
+    let server = Server::start(|_| Response::new(200).header("ETag", "\"abc\""));
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let err = task::block_on(bucket.compose_object(&[], "all.txt"))
+        .err()
+        .unwrap();
+    assert!(matches!(err.kind(), ErrorKind::InvalidInput(_)));
+    assert!(!err.is_retryable());
+
+    // No file can be created in a missing directory.
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("missing").join("a.txt");
+    let options = DownloadOptions::new();
+    let err = task::block_on(bucket.download_object_to_file("a.txt", &path, &options))
+        .err()
+        .unwrap();
+    assert!(matches!(err.kind(), ErrorKind::Io(_)));
+    assert!(!err.is_retryable());
+    assert!(err.source().is_some());
+}
@@ -13,7 +13,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use async_std::task;
 use minio::s3::Etag;
+use proptest::prelude::*;
 
 #[test]
 fn parse_etag() {
@@ -86,3 +88,51 @@ fn compute_from() {
         );
     }
 }
+
+#[test]
+fn compute_multipart() {
+    let tests = vec![
+        ("", 5, "59adb24ef3cdbe0297f05b395827453f-1"),
+        ("Hello World", 6, "f5caab02bb8180e6e93a9ebd342462db-2"),
+        ("Hello World", 11, "7b976cc68452e003eec7cb0eb631a19a-1"),
+        ("0123456789", 5, "9a6dbec798b1bfe66cc7659d2bb41720-2"),
+    ];
+
+    for (data, part_size, etag) in tests {
+        let a = etag.parse::<Etag>().unwrap();
+        let b = task::block_on(Etag::compute_multipart(&mut data.as_bytes(), part_size)).unwrap();
+        assert_eq!(a, b, "ETag of {:?} with part size {}", data, part_size);
+    }
+
+    let err = task::block_on(Etag::compute_multipart(&mut "data".as_bytes(), 0)).unwrap_err();
+    assert_eq!(std::io::ErrorKind::InvalidInput, err.kind());
+    let data = vec![0; 10001];
+    let err = task::block_on(Etag::compute_multipart(&mut data.as_slice(), 1)).unwrap_err();
+    assert_eq!(std::io::ErrorKind::InvalidInput, err.kind());
+}
+
+#[test]
+fn format_etag() {
+    let etag = "000102030405060708090a0b0c0d0e0f-7"
+        .parse::<Etag>()
+        .unwrap();
+    assert_eq!("000102030405060708090a0b0c0d0e0f-7", etag.to_string());
+    assert_eq!("000102030405060708090a0b0c0d0e0f-7", format!("{:x}", etag));
+    assert_eq!("000102030405060708090A0B0C0D0E0F-7", format!("{:X}", etag));
+}
+
+proptest! {
+    #[test]
+    fn etag_round_trip(bytes in any::<[u8; 16]>(), parts in proptest::option::of(1u16..=10000)) {
+        let mut s: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+        if let Some(n) = parts {
+            s.push_str(&format!("-{}", n));
+        }
+
+        let etag = s.parse::<Etag>().unwrap();
+        prop_assert_eq!(&s, &etag.to_string());
+        prop_assert_eq!(etag, etag.to_string().parse::<Etag>().unwrap());
+        prop_assert_eq!(etag, format!("{:X}", etag).parse::<Etag>().unwrap());
+        prop_assert_eq!(etag.parts(), parts);
+    }
+}
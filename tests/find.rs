@@ -0,0 +1,135 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use futures::TryStreamExt;
+use minio::s3::{find::ObjectFilter, Bucket, StorageClass};
+use time::macros::datetime;
+
+const LISTING: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+  <Name>my-bucket</Name>
+  <IsTruncated>false</IsTruncated>
+  <Contents>
+    <Key>small</Key>
+    <LastModified>2022-01-01T00:00:00.000Z</LastModified>
+    <ETag>"d41d8cd98f00b204e9800998ecf8427e"</ETag>
+    <Size>10</Size>
+    <StorageClass>STANDARD</StorageClass>
+  </Contents>
+  <Contents>
+    <Key>large-old</Key>
+    <LastModified>2021-01-01T00:00:00.000Z</LastModified>
+    <ETag>"d41d8cd98f00b204e9800998ecf8427e"</ETag>
+    <Size>5000</Size>
+    <StorageClass>GLACIER</StorageClass>
+  </Contents>
+  <Contents>
+    <Key>large-new</Key>
+    <LastModified>2022-06-01T00:00:00.000Z</LastModified>
+    <ETag>"d41d8cd98f00b204e9800998ecf8427e"</ETag>
+    <Size>5000</Size>
+    <StorageClass>STANDARD</StorageClass>
+  </Contents>
+</ListBucketResult>"#;
+
+const TAGGING: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Tagging xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+  <TagSet>
+    <Tag><Key>retention</Key><Value>1y</Value></Tag>
+    <Tag><Key>team</Key><Value>storage</Value></Tag>
+  </TagSet>
+</Tagging>"#;
+
+fn server() -> Server {
+    Server::start(|request| match request.query() {
+        Some("tagging=") if request.path() == "/my-bucket/large-new" => {
+            Response::new(200).body(TAGGING)
+        }
+        Some("tagging=") => Response::new(200).body("<Tagging><TagSet></TagSet></Tagging>"),
+        _ => Response::new(200).body(LISTING),
+    })
+}
+
+fn find(bucket: &Bucket, filter: ObjectFilter) -> Vec<String> {
+    task::block_on(
+        bucket
+            .find("", filter)
+            .map_ok(|object| object.key().to_owned())
+            .try_collect(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn find_by_metadata() {
+    let server = server();
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    assert_eq!(3, find(&bucket, ObjectFilter::new()).len());
+    assert_eq!(
+        vec!["large-old", "large-new"],
+        find(&bucket, ObjectFilter::new().size(1000..))
+    );
+    assert_eq!(
+        vec!["small"],
+        find(&bucket, ObjectFilter::new().size(..=10))
+    );
+    assert_eq!(
+        vec!["large-old"],
+        find(
+            &bucket,
+            ObjectFilter::new()
+                .size(1000..)
+                .modified_before(datetime!(2022-01-01 00:00 UTC))
+        )
+    );
+    assert_eq!(
+        vec!["large-old"],
+        find(
+            &bucket,
+            ObjectFilter::new().storage_class(StorageClass::Glacier)
+        )
+    );
+
+    // Without tag conditions, no tags are fetched.
+    assert!(server
+        .requests()
+        .iter()
+        .all(|r| r.query() != Some("tagging=")));
+}
+
+#[test]
+fn find_by_tags() {
+    let server = server();
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    assert_eq!(
+        vec!["large-new"],
+        find(&bucket, ObjectFilter::new().tag("retention"))
+    );
+    assert_eq!(
+        vec!["large-new"],
+        find(&bucket, ObjectFilter::new().tag_value("team", "storage"))
+    );
+    assert!(find(&bucket, ObjectFilter::new().tag_value("team", "compute")).is_empty());
+
+    let tags = task::block_on(bucket.get_object_tags("large-new")).unwrap();
+    assert_eq!(Some("1y"), tags.get("retention"));
+    assert_eq!(2, tags.len());
+}
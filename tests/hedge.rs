@@ -0,0 +1,109 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::{io::ReadExt, task};
+use common::{Response, Server};
+use minio::s3::{object::Content, Bucket, Hedging};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+const HEDGING: Duration = Duration::from_millis(100);
+const SLOW: Duration = Duration::from_secs(5);
+
+/// Answers the n-th request with the n-th of `delays` and the
+/// body `request <n>`.
+fn server(delays: &'static [Duration]) -> Server {
+    let count = Arc::new(AtomicUsize::new(0));
+    Server::start(move |_| {
+        let n = count.fetch_add(1, Ordering::SeqCst);
+        Response::new(200)
+            .header("ETag", "\"b10a8db164e0754105b7a99be72e3fe5\"")
+            .body(format!("request {}", n))
+            .delay(delays.get(n).copied().unwrap_or_default())
+    })
+}
+
+fn get(bucket: &Bucket) -> (String, Duration) {
+    let start = Instant::now();
+    let object = task::block_on(bucket.get_object("object")).unwrap();
+    let mut content = String::new();
+    task::block_on(Content::from(object).read_to_string(&mut content)).unwrap();
+    (content, start.elapsed())
+}
+
+#[test]
+fn fast_requests_are_not_hedged() {
+    let server = server(&[]);
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials())
+        .unwrap()
+        .with_hedging(Hedging::new(HEDGING));
+
+    assert_eq!("request 0", get(&bucket).0);
+    task::block_on(task::sleep(2 * HEDGING));
+    assert_eq!(1, server.requests().len());
+}
+
+#[test]
+fn hedge_after_delay() {
+    let server = server(&[SLOW]);
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials())
+        .unwrap()
+        .with_hedging(Hedging::new(HEDGING));
+
+    // The hedge wins and the slow request is dropped instead of
+    // being waited for.
+    let (content, elapsed) = get(&bucket);
+    assert_eq!("request 1", content);
+    assert!(elapsed >= HEDGING, "hedged too early: {:?}", elapsed);
+    assert!(elapsed < SLOW, "waited for the slow request: {:?}", elapsed);
+    assert_eq!(2, server.requests().len());
+}
+
+#[test]
+fn first_success_wins() {
+    const DELAYS: &[Duration] = &[Duration::from_millis(300), SLOW];
+    let server = server(DELAYS);
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials())
+        .unwrap()
+        .with_hedging(Hedging::new(HEDGING));
+
+    // The original request completes before the hedge.
+    let (content, elapsed) = get(&bucket);
+    assert_eq!("request 0", content);
+    assert!(elapsed < SLOW, "waited for the hedge: {:?}", elapsed);
+    assert_eq!(2, server.requests().len());
+}
+
+#[test]
+fn writes_are_not_hedged() {
+    const DELAYS: &[Duration] = &[Duration::from_millis(500)];
+    let server = server(DELAYS);
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials())
+        .unwrap()
+        .with_hedging(Hedging::new(HEDGING));
+
+    let start = Instant::now();
+    task::block_on(bucket.put_object_bytes("object", "Hello World")).unwrap();
+    assert!(start.elapsed() >= DELAYS[0]);
+    assert_eq!(1, server.requests().len());
+    assert_eq!("PUT", server.requests()[0].method);
+}
@@ -0,0 +1,111 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::{inventory::*, Bucket};
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn inventory_configuration() {
+    let stored = Arc::new(Mutex::new(Vec::new()));
+    let server = Server::start({
+        let stored = stored.clone();
+        move |request| match request.method.as_str() {
+            "PUT" => {
+                *stored.lock().unwrap() = request.body.clone();
+                Response::new(200)
+            }
+            "DELETE" => Response::new(204),
+            _ => Response::new(200).body(stored.lock().unwrap().clone()),
+        }
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let destination = Destination::new("arn:aws:s3:::inventory-reports", Format::Csv)
+        .with_account_id("123456789012")
+        .with_prefix("reports & more")
+        .with_encryption(Encryption::SseKms(
+            "arn:aws:kms:us-east-1:123456789012:key/1".into(),
+        ));
+    let config = InventoryConfiguration::new("report", destination, Schedule::Weekly)
+        .with_included_versions(IncludedVersions::All)
+        .with_prefix("photos/")
+        .with_optional_fields([OptionalField::Size, OptionalField::ETag]);
+
+    task::block_on(bucket.put_inventory_configuration(&config)).unwrap();
+    let requests = server.requests();
+    assert_eq!(Some("inventory=&id=report"), requests[0].query());
+    let body = String::from_utf8(requests[0].body.clone()).unwrap();
+    assert!(body.contains("<Prefix>reports &amp; more</Prefix>"));
+    assert!(
+        body.contains("<OptionalFields><Field>Size</Field><Field>ETag</Field></OptionalFields>")
+    );
+
+    assert_eq!(
+        config,
+        task::block_on(bucket.get_inventory_configuration("report")).unwrap()
+    );
+
+    task::block_on(bucket.delete_inventory_configuration("report")).unwrap();
+    assert_eq!("DELETE", server.requests()[2].method);
+}
+
+#[test]
+fn list_inventory_configurations() {
+    fn configuration(id: &str) -> String {
+        format!(
+            "<InventoryConfiguration><Id>{}</Id><IsEnabled>false</IsEnabled>\
+             <Destination><S3BucketDestination><Bucket>arn:aws:s3:::reports</Bucket>\
+             <Format>Parquet</Format><Encryption><SSE-S3/></Encryption></S3BucketDestination></Destination>\
+             <Schedule><Frequency>Daily</Frequency></Schedule>\
+             <IncludedObjectVersions>Current</IncludedObjectVersions></InventoryConfiguration>",
+            id
+        )
+    }
+
+    let server = Server::start(move |request| {
+        let body = match request.query() {
+            Some("inventory=") => format!(
+                "<ListInventoryConfigurationsResult>{}{}<IsTruncated>true</IsTruncated>\
+                 <NextContinuationToken>next/page</NextContinuationToken></ListInventoryConfigurationsResult>",
+                configuration("first"),
+                configuration("second"),
+            ),
+            _ => format!(
+                "<ListInventoryConfigurationsResult>{}<IsTruncated>false</IsTruncated></ListInventoryConfigurationsResult>",
+                configuration("third"),
+            ),
+        };
+        Response::new(200).body(body)
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let configs = task::block_on(bucket.list_inventory_configurations()).unwrap();
+    let ids: Vec<&str> = configs.iter().map(InventoryConfiguration::id).collect();
+    assert_eq!(vec!["first", "second", "third"], ids);
+    assert!(!configs[0].is_enabled());
+    assert_eq!(Format::Parquet, configs[0].destination().format());
+    assert_eq!(
+        Some(&Encryption::SseS3),
+        configs[0].destination().encryption()
+    );
+    assert_eq!(
+        Some("inventory=&continuation-token=next%2Fpage"),
+        server.requests()[1].query()
+    );
+}
@@ -0,0 +1,67 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::{Bucket, ObjectKey};
+
+#[test]
+fn parse_key() {
+    let tests = vec![
+        "object",
+        "photos/2022/beach.jpg",
+        "/photos//2022/",
+        "photos\\2022\\beach.jpg",
+        "a b?c#d%e",
+        "..a/b..",
+    ];
+    for key in tests {
+        assert_eq!(key, key.parse::<ObjectKey>().unwrap().as_str());
+    }
+
+    let long = "a".repeat(ObjectKey::MAX_LEN + 1);
+    let tests = vec!["", "a/./b", "../a", "a/..", "a\u{0}b", "a\nb", &long];
+    for key in tests {
+        assert!(
+            key.parse::<ObjectKey>().is_err(),
+            "Parsed invalid object key without any error: {:?}",
+            key
+        );
+    }
+    assert!("a".repeat(ObjectKey::MAX_LEN).parse::<ObjectKey>().is_ok());
+}
+
+#[test]
+fn encode_key() {
+    let server = Server::start(|request| match request.method.as_str() {
+        "DELETE" => Response::new(204),
+        _ => Response::new(200),
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    task::block_on(bucket.put_object_bytes("dir/a b?c#d%e", "data")).unwrap();
+    let err = task::block_on(bucket.put_object_bytes("dir/../secret", "data")).unwrap_err();
+    assert!(err.to_string().starts_with("invalid S3 object key"));
+
+    // Keys are sent verbatim and never refer to another object.
+    task::block_on(bucket.delete_object("/a//b\\c")).unwrap();
+
+    let requests = server.requests();
+    assert_eq!(2, requests.len());
+    assert_eq!("/my-bucket/dir/a%20b%3Fc%23d%25e", requests[0].path());
+    assert_eq!("/my-bucket//a//b%5Cc", requests[1].path());
+}
@@ -0,0 +1,194 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use futures::TryStreamExt;
+use minio::s3::{Bucket, StorageClass};
+
+const PAGE_1: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+  <Name>my-bucket</Name>
+  <Prefix>logs/</Prefix>
+  <KeyCount>2</KeyCount>
+  <MaxKeys>2</MaxKeys>
+  <IsTruncated>true</IsTruncated>
+  <NextContinuationToken>page-2</NextContinuationToken>
+  <Contents>
+    <Key>logs/a.log</Key>
+    <LastModified>2022-03-01T10:00:00.000Z</LastModified>
+    <ETag>"d41d8cd98f00b204e9800998ecf8427e"</ETag>
+    <Size>100</Size>
+    <StorageClass>STANDARD</StorageClass>
+  </Contents>
+  <Contents>
+    <Key>logs/b.log</Key>
+    <LastModified>2022-03-02T10:00:00.000Z</LastModified>
+    <ETag>"b10a8db164e0754105b7a99be72e3fe5-2"</ETag>
+    <Size>200</Size>
+    <StorageClass>GLACIER</StorageClass>
+  </Contents>
+</ListBucketResult>"#;
+
+const PAGE_2: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+  <Name>my-bucket</Name>
+  <Prefix>logs/</Prefix>
+  <KeyCount>1</KeyCount>
+  <MaxKeys>2</MaxKeys>
+  <IsTruncated>false</IsTruncated>
+  <Contents>
+    <Key>logs/c.log</Key>
+    <LastModified>2022-03-03T10:00:00.000Z</LastModified>
+    <ETag>"d41d8cd98f00b204e9800998ecf8427e"</ETag>
+    <Size>300</Size>
+    <StorageClass>STANDARD</StorageClass>
+  </Contents>
+</ListBucketResult>"#;
+
+fn listing(request: &common::Request) -> Response {
+    match request.query() {
+        Some(query) if query.contains("continuation-token=page-2") => {
+            Response::new(200).body(PAGE_2)
+        }
+        _ => Response::new(200).body(PAGE_1),
+    }
+}
+
+#[test]
+fn list_objects() {
+    let server = Server::start(listing);
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let page = task::block_on(bucket.list_objects("logs/", None)).unwrap();
+    assert!(page.is_truncated());
+    assert_eq!(Some("page-2"), page.continuation_token());
+    assert_eq!(2, page.objects().len());
+    assert_eq!("logs/b.log", page.objects()[1].key());
+    assert_eq!(200, page.objects()[1].size());
+    assert_eq!(Some(2), page.objects()[1].etag().parts());
+    assert_eq!(StorageClass::Glacier, page.objects()[1].storage_class());
+
+    let page = task::block_on(bucket.list_objects("logs/", Some("page-2"))).unwrap();
+    assert!(!page.is_truncated());
+    assert_eq!(1, page.objects().len());
+
+    let requests = server.requests();
+    assert_eq!("/my-bucket", requests[0].path());
+    assert_eq!(Some("list-type=2&prefix=logs%2F"), requests[0].query());
+}
+
+#[test]
+fn list_objects_stream() {
+    let server = Server::start(listing);
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let keys: Vec<String> = task::block_on(
+        bucket
+            .list_objects_stream("logs/")
+            .map_ok(|object| object.key().to_owned())
+            .try_collect(),
+    )
+    .unwrap();
+    assert_eq!(vec!["logs/a.log", "logs/b.log", "logs/c.log"], keys);
+    assert_eq!(2, server.requests().len());
+}
+
+#[test]
+fn disk_usage() {
+    let server = Server::start(listing);
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let usage = task::block_on(bucket.disk_usage("logs/")).unwrap();
+    assert_eq!(3, usage.objects());
+    assert_eq!(600, usage.size());
+    assert_eq!(2, usage.storage_class(StorageClass::Standard).objects());
+    assert_eq!(400, usage.storage_class(StorageClass::Standard).size());
+    assert_eq!(200, usage.storage_class(StorageClass::Glacier).size());
+
+    let progress: Vec<u64> = task::block_on(
+        bucket
+            .disk_usage_stream("logs/")
+            .map_ok(|usage| usage.objects())
+            .try_collect(),
+    )
+    .unwrap();
+    assert_eq!(vec![2, 3], progress);
+}
+
+#[test]
+fn list_objects_encoding() {
+    let server = Server::start(listing);
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    task::block_on(bucket.list_objects("my logs/a+b", Some("x=y"))).unwrap();
+
+    let requests = server.requests();
+    assert_eq!(
+        Some("list-type=2&prefix=my%20logs%2Fa%2Bb&continuation-token=x%3Dy"),
+        requests[0].query()
+    );
+}
+
+#[test]
+fn list_large_page() {
+    // A page with many entries, escaped keys and the continuation
+    // token after the entries, which exceeds a single read.
+    fn page(first: bool) -> String {
+        let mut xml = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!-- listing -->\
+             <ListBucketResult xmlns=\"http://s3.amazonaws.com/doc/2006-03-01/\"><Name>my-bucket</Name>",
+        );
+        for i in 0..1000 {
+            xml.push_str(&format!(
+                "<Contents><Key>dir/a&amp;b-{:04}-{}</Key><LastModified>2022-03-01T10:00:00.000Z</LastModified>\
+                 <ETag>&quot;d41d8cd98f00b204e9800998ecf8427e&quot;</ETag><Size>1</Size>\
+                 <Owner><ID>owner</ID><DisplayName/></Owner><StorageClass>STANDARD</StorageClass></Contents>",
+                i,
+                "x".repeat(200)
+            ));
+        }
+        xml.push_str("<CommonPrefixes><Prefix>dir/sub/</Prefix></CommonPrefixes>");
+        match first {
+            true => xml.push_str(
+                "<IsTruncated>true</IsTruncated><NextContinuationToken>a+b/c==</NextContinuationToken>",
+            ),
+            false => xml.push_str("<IsTruncated>false</IsTruncated>"),
+        }
+        xml.push_str("</ListBucketResult>");
+        xml
+    }
+
+    let server = Server::start(|request| match request.query() {
+        Some(query) if query.contains("continuation-token") => Response::new(200).body(page(false)),
+        _ => Response::new(200).body(page(true)),
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let listing = task::block_on(bucket.list_objects("dir/", None)).unwrap();
+    assert_eq!(1000, listing.objects().len());
+    assert!(listing.objects()[0].key().starts_with("dir/a&b-0000-"));
+    assert_eq!(vec![String::from("dir/sub/")], listing.common_prefixes());
+    assert_eq!(Some("a+b/c=="), listing.continuation_token());
+
+    let usage = task::block_on(bucket.disk_usage("dir/")).unwrap();
+    assert_eq!(2000, usage.objects());
+    assert_eq!(
+        Some("list-type=2&prefix=dir%2F&continuation-token=a%2Bb%2Fc%3D%3D"),
+        server.requests()[2].query()
+    );
+}
@@ -0,0 +1,92 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::{bucket::Configuration, lock::*, Bucket};
+
+const CONFIGURATION: &str = r#"<ObjectLockConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+  <ObjectLockEnabled>Enabled</ObjectLockEnabled>
+  <Rule><DefaultRetention><Mode>COMPLIANCE</Mode><Years>7</Years></DefaultRetention></Rule>
+</ObjectLockConfiguration>"#;
+
+#[test]
+fn create_with_object_lock() {
+    let server = Server::start(|_| Response::new(200));
+
+    let config = Configuration::default().with_object_lock(true);
+    task::block_on(Bucket::create(
+        "my-bucket",
+        server.region(),
+        common::credentials(),
+        config,
+    ))
+    .unwrap();
+    let request = &server.requests()[0];
+    assert_eq!("PUT", request.method);
+    assert_eq!(
+        Some("true"),
+        request.header("x-amz-bucket-object-lock-enabled")
+    );
+
+    task::block_on(Bucket::create(
+        "other-bucket",
+        server.region(),
+        common::credentials(),
+        Configuration::default(),
+    ))
+    .unwrap();
+    assert_eq!(
+        None,
+        server.requests()[1].header("x-amz-bucket-object-lock-enabled")
+    );
+}
+
+#[test]
+fn object_lock_configuration() {
+    let server = Server::start(|request| match (request.method.as_str(), request.path()) {
+        ("GET", "/my-bucket/") => Response::new(200).body(CONFIGURATION),
+        ("GET", _) => Response::error(404, "ObjectLockConfigurationNotFoundError"),
+        _ => Response::new(200),
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let config = task::block_on(bucket.get_object_lock_configuration())
+        .unwrap()
+        .unwrap();
+    assert_eq!(Some("object-lock="), server.requests()[0].query());
+    let retention = config.default_retention().unwrap();
+    assert_eq!(RetentionMode::Compliance, retention.mode());
+    assert_eq!(RetentionPeriod::Years(7), retention.period());
+
+    let config = ObjectLockConfiguration::new()
+        .with_default_retention(RetentionMode::Governance, RetentionPeriod::Days(30));
+    task::block_on(bucket.put_object_lock_configuration(&config)).unwrap();
+    let request = &server.requests()[1];
+    assert_eq!("PUT", request.method);
+    assert!(request.header("content-md5").is_some());
+    assert_eq!(
+        r#"<ObjectLockConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/"><ObjectLockEnabled>Enabled</ObjectLockEnabled><Rule><DefaultRetention><Mode>GOVERNANCE</Mode><Days>30</Days></DefaultRetention></Rule></ObjectLockConfiguration>"#.as_bytes(),
+        request.body.as_slice()
+    );
+
+    let bucket = Bucket::new("unlocked", server.region(), common::credentials()).unwrap();
+    assert_eq!(
+        None,
+        task::block_on(bucket.get_object_lock_configuration()).unwrap()
+    );
+}
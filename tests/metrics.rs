@@ -0,0 +1,89 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::{metrics::*, Bucket};
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn metrics_configuration() {
+    let stored = Arc::new(Mutex::new(Vec::new()));
+    let server = Server::start({
+        let stored = stored.clone();
+        move |request| match request.method.as_str() {
+            "PUT" => {
+                *stored.lock().unwrap() = request.body.clone();
+                Response::new(200)
+            }
+            "DELETE" => Response::new(204),
+            _ => Response::new(200).body(stored.lock().unwrap().clone()),
+        }
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let filter = MetricsFilter::new()
+        .with_prefix("photos/")
+        .with_tag("team", "media & web");
+    let config = MetricsConfiguration::new("photos").with_filter(filter);
+
+    task::block_on(bucket.put_metrics_configuration(&config)).unwrap();
+    let requests = server.requests();
+    assert_eq!(Some("metrics=&id=photos"), requests[0].query());
+    let body = String::from_utf8(requests[0].body.clone()).unwrap();
+    assert!(body.contains(
+        "<Filter><And><Prefix>photos/</Prefix>\
+         <Tag><Key>team</Key><Value>media &amp; web</Value></Tag></And></Filter>"
+    ));
+
+    assert_eq!(
+        config,
+        task::block_on(bucket.get_metrics_configuration("photos")).unwrap()
+    );
+
+    task::block_on(bucket.delete_metrics_configuration("photos")).unwrap();
+    assert_eq!("DELETE", server.requests()[2].method);
+}
+
+#[test]
+fn list_metrics_configurations() {
+    let server = Server::start(move |request| {
+        let body = match request.query() {
+            Some("metrics=") => "<ListMetricsConfigurationsResult>\
+                 <MetricsConfiguration><Id>all</Id></MetricsConfiguration>\
+                 <MetricsConfiguration><Id>logs</Id><Filter><Prefix>logs/</Prefix></Filter></MetricsConfiguration>\
+                 <IsTruncated>true</IsTruncated><NextContinuationToken>next</NextContinuationToken>\
+                 </ListMetricsConfigurationsResult>",
+            _ => "<ListMetricsConfigurationsResult>\
+                 <MetricsConfiguration><Id>tagged</Id><Filter><Tag><Key>env</Key><Value>prod</Value></Tag></Filter></MetricsConfiguration>\
+                 <IsTruncated>false</IsTruncated></ListMetricsConfigurationsResult>",
+        };
+        Response::new(200).body(body)
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let configs = task::block_on(bucket.list_metrics_configurations()).unwrap();
+    let ids: Vec<&str> = configs.iter().map(MetricsConfiguration::id).collect();
+    assert_eq!(vec!["all", "logs", "tagged"], ids);
+    assert!(configs[0].filter().is_empty());
+    assert_eq!(Some("logs/"), configs[1].filter().prefix());
+    assert_eq!(Some("prod"), configs[2].filter().tags().get("env"));
+    assert_eq!(
+        Some("metrics=&continuation-token=next"),
+        server.requests()[1].query()
+    );
+}
@@ -0,0 +1,190 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::{
+    middleware::{async_trait, Middleware},
+    Client, Result, RetryPolicy,
+};
+use std::{
+    error::Error as _,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+use surf::http::{Method, Request, StatusCode, Url};
+
+/// A middleware that adds a header to requests and records
+/// the requests and responses it sees.
+#[derive(Debug)]
+struct Recorder {
+    name: &'static str,
+    log: Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait]
+impl Middleware for Recorder {
+    async fn on_request(&self, request: &mut Request) -> Result<()> {
+        request.insert_header(format!("x-amz-meta-{}", self.name).as_str(), "1");
+        self.log
+            .lock()
+            .unwrap()
+            .push(format!("{} {}", self.name, request.url().path()));
+        Ok(())
+    }
+
+    async fn on_response(
+        &self,
+        method: Method,
+        _: &Url,
+        response: &mut surf::http::Response,
+    ) -> Result<()> {
+        self.log
+            .lock()
+            .unwrap()
+            .push(format!("{} {} {}", self.name, method, response.status()));
+        Ok(())
+    }
+}
+
+/// A middleware that fails the first responses with
+/// `503 Service Unavailable`.
+#[derive(Debug)]
+struct Chaos {
+    failures: AtomicUsize,
+}
+
+#[async_trait]
+impl Middleware for Chaos {
+    async fn on_response(
+        &self,
+        _: Method,
+        _: &Url,
+        response: &mut surf::http::Response,
+    ) -> Result<()> {
+        let fail = self
+            .failures
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .is_ok();
+        if fail {
+            response.set_status(StatusCode::ServiceUnavailable);
+        }
+        Ok(())
+    }
+}
+
+/// A middleware that rejects all requests.
+#[derive(Debug)]
+struct Reject;
+
+#[async_trait]
+impl Middleware for Reject {
+    async fn on_request(&self, _: &mut Request) -> Result<()> {
+        Err(surf::Error::from_str(StatusCode::Forbidden, "rejected by middleware").into())
+    }
+}
+
+fn ok(_: &common::Request) -> Response {
+    Response::new(200).header("ETag", "\"8d777f385d3dfec8815d20f7496026dc\"")
+}
+
+#[test]
+fn middleware_order() {
+    let server = Server::start(ok);
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let recorder = |name| Recorder {
+        name,
+        log: log.clone(),
+    };
+    let client = Client::new(server.region(), common::credentials())
+        .with_middleware(recorder("outer"))
+        .with_middleware(recorder("inner"));
+
+    task::block_on(
+        client
+            .bucket("my-bucket")
+            .unwrap()
+            .put_object_bytes("a.txt", "a"),
+    )
+    .unwrap();
+
+    assert_eq!(
+        vec![
+            "outer /my-bucket/a.txt",
+            "inner /my-bucket/a.txt",
+            "inner PUT 200",
+            "outer PUT 200"
+        ],
+        *log.lock().unwrap()
+    );
+    // Headers added by middleware are signed.
+    let request = &server.requests()[0];
+    assert_eq!(Some("1"), request.header("x-amz-meta-outer"));
+    assert_eq!(Some("1"), request.header("x-amz-meta-inner"));
+    let authorization = request.header("authorization").unwrap();
+    assert!(authorization.contains("x-amz-meta-inner;x-amz-meta-outer"));
+}
+
+#[test]
+fn retried_responses() {
+    let server = Server::start(ok);
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let client = Client::new(server.region(), common::credentials())
+        .with_retry_policy(RetryPolicy::new().with_base_delay(Duration::from_millis(1)))
+        .with_middleware(Recorder {
+            name: "audit",
+            log: log.clone(),
+        })
+        .with_middleware(Chaos {
+            failures: AtomicUsize::new(2),
+        });
+
+    task::block_on(
+        client
+            .bucket("my-bucket")
+            .unwrap()
+            .put_object_bytes("a.txt", "a"),
+    )
+    .unwrap();
+
+    // Each attempt passes the middleware.
+    assert_eq!(3, server.requests().len());
+    assert_eq!(
+        vec!["audit PUT 503", "audit PUT 503", "audit PUT 200"],
+        log.lock().unwrap()[1..]
+    );
+}
+
+#[test]
+fn rejected_requests() {
+    let server = Server::start(ok);
+    let bucket = Client::new(server.region(), common::credentials())
+        .with_middleware(Reject)
+        .bucket("my-bucket")
+        .unwrap();
+
+    let err = task::block_on(bucket.put_object_bytes("a.txt", "a")).unwrap_err();
+    assert!(err
+        .source()
+        .unwrap()
+        .to_string()
+        .contains("rejected by middleware"));
+    assert!(server.requests().is_empty());
+}
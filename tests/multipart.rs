@@ -0,0 +1,612 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::{io::Cursor, task};
+use common::{Response, Server};
+use minio::s3::{
+    clock::FixedClock, compose::ComposeSource, multipart::MultipartUpload, Bucket, BucketName,
+    ChecksumAlgorithm, Client, ErrorCode, Etag, ObjectKey, Payload, PutObjectOptions,
+};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use surf::http::Mime;
+use time::macros::datetime;
+
+const UPLOAD_ID: &str = "VXBsb2FkIElEIGZvciBlbHZpbmcncyBteS1tb3ZpZS5tMnRzIHVwbG9hZA";
+const PART_ETAG: &str = "\"b54357faf0632cce46e942fa68356b38\"";
+const OBJECT_ETAG: &str = "\"3858f62230ac3c915f300c664312c11f-2\"";
+
+/// Answers multipart requests and fails the upload of
+/// part 2 with `500 Internal Server Error` until it has
+/// been sent `failures` times.
+fn server(failures: usize) -> (Server, Arc<AtomicUsize>) {
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let counter = attempts.clone();
+    let server = Server::start(move |request| {
+        match (request.method.as_str(), request.query()) {
+        ("POST", Some("uploads=")) => Response::new(200).body(format!(
+            "<InitiateMultipartUploadResult><Bucket>my-bucket</Bucket><Key>large.bin</Key><UploadId>{}</UploadId></InitiateMultipartUploadResult>",
+            UPLOAD_ID
+        )),
+        ("PUT", Some(query)) if query.starts_with("partNumber=2&") => {
+            if counter.fetch_add(1, Ordering::SeqCst) < failures {
+                Response::error(500, "InternalError")
+            } else {
+                Response::new(200).header("ETag", PART_ETAG)
+            }
+        }
+        ("PUT", _) => Response::new(200).header("ETag", PART_ETAG),
+        ("POST", _) => Response::new(200).header("x-amz-version-id", "v1").body(format!(
+            "<CompleteMultipartUploadResult><Key>large.bin</Key><ETag>{}</ETag></CompleteMultipartUploadResult>",
+            OBJECT_ETAG.replace('"', "&quot;")
+        )),
+        ("DELETE", _) => Response::new(204),
+        _ => Response::new(400),
+    }
+    });
+    (server, attempts)
+}
+
+#[test]
+fn multipart_upload() {
+    let (server, attempts) = server(1);
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let upload = task::block_on(bucket.create_multipart_upload("large.bin")).unwrap();
+    assert_eq!(UPLOAD_ID, upload.upload_id());
+
+    let first = task::block_on(bucket.upload_part(&upload, 1, "first")).unwrap();
+    let second = task::block_on(
+        bucket.upload_part_from(&upload, 2, || async { Ok(Cursor::new(b"second".to_vec())) }),
+    )
+    .unwrap();
+    assert_eq!(2, attempts.load(Ordering::SeqCst));
+    assert_eq!(2, second.number());
+    assert_eq!(&PART_ETAG.parse::<Etag>().unwrap(), second.etag());
+
+    let result =
+        task::block_on(bucket.complete_multipart_upload(&upload, &[second, first])).unwrap();
+    assert_eq!(Some(&OBJECT_ETAG.parse().unwrap()), result.etag());
+    assert_eq!(Some("v1"), result.version_id());
+
+    // Only the failed part has been sent again.
+    let requests = server.requests();
+    assert_eq!(5, requests.len());
+    assert_eq!(b"first", requests[1].body.as_slice());
+    assert_eq!(b"second", requests[2].body.as_slice());
+    assert_eq!(b"second", requests[3].body.as_slice());
+
+    let complete = String::from_utf8(requests[4].body.clone()).unwrap();
+    assert_eq!(
+        Some(format!("uploadId={}", UPLOAD_ID).as_str()),
+        requests[4].query()
+    );
+    assert!(
+        complete.find("<PartNumber>1</PartNumber>") < complete.find("<PartNumber>2</PartNumber>")
+    );
+}
+
+#[test]
+fn multipart_part_attempts() {
+    let (server, attempts) = server(usize::MAX);
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let upload = task::block_on(bucket.create_multipart_upload("large.bin"))
+        .unwrap()
+        .with_part_attempts(2);
+    let err = task::block_on(bucket.upload_part(&upload, 2, "second")).unwrap_err();
+    assert_eq!(Some(ErrorCode::InternalError), err.code());
+    assert_eq!(2, attempts.load(Ordering::SeqCst));
+
+    task::block_on(bucket.abort_multipart_upload(&upload)).unwrap();
+    assert_eq!("DELETE", server.requests().last().unwrap().method);
+}
+
+#[test]
+fn multipart_upload_resume() {
+    let (server, _) = server(0);
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let mut upload = task::block_on(bucket.create_multipart_upload("large.bin"))
+        .unwrap()
+        .with_part_attempts(5);
+    upload.add_part(task::block_on(bucket.upload_part(&upload, 1, "first")).unwrap());
+    let state = serde_json::to_string(&upload).unwrap();
+    drop(upload);
+
+    let mut upload: MultipartUpload = serde_json::from_str(&state).unwrap();
+    assert_eq!(UPLOAD_ID, upload.upload_id());
+    assert_eq!("large.bin", upload.key().as_str());
+    assert_eq!(5, upload.part_attempts());
+    assert!(upload.part(1).is_some());
+    assert!(upload.part(2).is_none());
+
+    upload.add_part(task::block_on(bucket.upload_part(&upload, 2, "second")).unwrap());
+    upload.add_part(task::block_on(bucket.upload_part(&upload, 1, "first")).unwrap());
+    assert_eq!(
+        vec![1, 2],
+        upload
+            .parts()
+            .iter()
+            .map(|p| p.number())
+            .collect::<Vec<_>>()
+    );
+
+    task::block_on(bucket.complete_multipart_upload(&upload, upload.parts())).unwrap();
+    let complete = server.requests().pop().unwrap();
+    assert_eq!("POST", complete.method);
+    assert!(String::from_utf8(complete.body)
+        .unwrap()
+        .contains("<PartNumber>2</PartNumber>"));
+}
+
+#[test]
+fn multipart_upload_invalid_state() {
+    let state = r#"{"key":"large.bin","upload_id":"abc","parts":[{"number":1,"etag":"xyz"}]}"#;
+    assert!(serde_json::from_str::<MultipartUpload>(state).is_err());
+
+    let state = r#"{"key":"large.bin","upload_id":"abc"}"#;
+    let upload: MultipartUpload = serde_json::from_str(state).unwrap();
+    assert_eq!(
+        MultipartUpload::DEFAULT_PART_ATTEMPTS,
+        upload.part_attempts()
+    );
+    assert!(upload.parts().is_empty());
+}
+
+const PART_SIZE: usize = MultipartUpload::MIN_PART_SIZE as usize;
+
+/// Returns the sizes of all uploaded parts.
+fn part_sizes(server: &Server) -> Vec<usize> {
+    server
+        .requests()
+        .iter()
+        .filter(|request| request.method == "PUT")
+        .map(|request| request.body.len())
+        .collect()
+}
+
+#[test]
+fn put_object_streamed() {
+    let (server, attempts) = server(1);
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials())
+        .unwrap()
+        .with_part_size(PART_SIZE as u64);
+
+    let data = vec![7u8; 2 * PART_SIZE + 100];
+    let result =
+        task::block_on(bucket.put_object_streamed("large.bin", Cursor::new(data))).unwrap();
+    assert_eq!(Some(&OBJECT_ETAG.parse().unwrap()), result.etag());
+
+    // The failed second part has been sent again.
+    assert_eq!(2, attempts.load(Ordering::SeqCst));
+    assert_eq!(
+        vec![PART_SIZE, PART_SIZE, PART_SIZE, 100],
+        part_sizes(&server)
+    );
+    let requests = server.requests();
+    let complete = String::from_utf8(requests.last().unwrap().body.clone()).unwrap();
+    assert_eq!(3, complete.matches("<Part>").count());
+}
+
+#[test]
+fn put_object_streamed_with_checksum() {
+    let (server, _) = server(0);
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials())
+        .unwrap()
+        .with_part_size(PART_SIZE as u64);
+    let options = PutObjectOptions::new().with_checksum(ChecksumAlgorithm::Crc32c);
+
+    let data = vec![7u8; PART_SIZE + 100];
+    task::block_on(bucket.put_object_streamed_with_options(
+        "large.bin",
+        Cursor::new(data.clone()),
+        &options,
+    ))
+    .unwrap();
+
+    // The upload declares the algorithm and every part is sent,
+    // and completed, with its checksum.
+    let requests = server.requests();
+    assert_eq!(
+        Some("CRC32C"),
+        requests[0].header("x-amz-checksum-algorithm")
+    );
+    let complete = String::from_utf8(requests.last().unwrap().body.clone()).unwrap();
+    for (part, data) in requests[1..3].iter().zip(data.chunks(PART_SIZE)) {
+        let checksum = ChecksumAlgorithm::Crc32c.checksum(data);
+        assert_eq!(
+            Some(checksum.as_str()),
+            part.header("x-amz-checksum-crc32c")
+        );
+        assert!(complete.contains(&format!("<ChecksumCRC32C>{}</ChecksumCRC32C>", checksum)));
+    }
+}
+
+#[test]
+fn put_object_streamed_single_part() {
+    let (server, _) = server(0);
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials())
+        .unwrap()
+        .with_part_size(PART_SIZE as u64);
+
+    let data = vec![1u8; 1024];
+    task::block_on(bucket.put_object_streamed("small.bin", Cursor::new(data))).unwrap();
+    let requests = server.requests();
+    assert_eq!(1, requests.len());
+    assert_eq!(None, requests[0].query());
+    assert_eq!(1024, requests[0].body.len());
+}
+
+#[test]
+fn put_object_streamed_part_size_multiple() {
+    let (server, _) = server(0);
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials())
+        .unwrap()
+        .with_part_size(PART_SIZE as u64);
+
+    // No empty part is uploaded at the end.
+    let data = vec![7u8; 2 * PART_SIZE];
+    task::block_on(bucket.put_object_streamed("large.bin", Cursor::new(data))).unwrap();
+    assert_eq!(vec![PART_SIZE, PART_SIZE], part_sizes(&server));
+}
+
+#[test]
+fn put_object_streamed_abort() {
+    struct Failing(usize);
+
+    impl async_std::io::Read for Failing {
+        fn poll_read(
+            mut self: std::pin::Pin<&mut Self>,
+            _: &mut std::task::Context<'_>,
+            buf: &mut [u8],
+        ) -> std::task::Poll<std::io::Result<usize>> {
+            if self.0 == 0 {
+                return std::task::Poll::Ready(Err(std::io::ErrorKind::BrokenPipe.into()));
+            }
+            let n = buf.len().min(self.0);
+            self.0 -= n;
+            std::task::Poll::Ready(Ok(n))
+        }
+    }
+
+    let (server, _) = server(0);
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials())
+        .unwrap()
+        .with_part_size(PART_SIZE as u64);
+    let reader = Failing(PART_SIZE + 10);
+    assert!(task::block_on(bucket.put_object_streamed("large.bin", reader)).is_err());
+    assert_eq!("DELETE", server.requests().last().unwrap().method);
+}
+
+#[test]
+fn put_object_streamed_abort_on_complete() {
+    let server = Server::start(|request| {
+        match (request.method.as_str(), request.query()) {
+        ("POST", Some("uploads=")) => Response::new(200).body(format!(
+            "<InitiateMultipartUploadResult><UploadId>{}</UploadId></InitiateMultipartUploadResult>",
+            UPLOAD_ID
+        )),
+        ("PUT", _) => Response::new(200).header("ETag", PART_ETAG),
+        ("POST", _) => Response::error(400, "InvalidPart"),
+        _ => Response::new(204),
+    }
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials())
+        .unwrap()
+        .with_part_size(PART_SIZE as u64);
+
+    // The upload is aborted if it cannot be completed.
+    let data = vec![7u8; PART_SIZE + 100];
+    assert!(task::block_on(bucket.put_object_streamed("large.bin", Cursor::new(data))).is_err());
+    let abort = server.requests().pop().unwrap();
+    assert_eq!("DELETE", abort.method);
+    assert_eq!(
+        Some(format!("uploadId={}", UPLOAD_ID).as_str()),
+        abort.query()
+    );
+}
+
+/// A payload whose length is not known in advance, like a pipe.
+struct Unsized(Cursor<Vec<u8>>);
+
+impl async_std::io::Read for Unsized {
+    fn poll_read(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        buf: &mut [u8],
+    ) -> std::task::Poll<std::io::Result<usize>> {
+        std::pin::Pin::new(&mut self.0).poll_read(cx, buf)
+    }
+}
+
+impl Payload for Unsized {}
+
+#[test]
+fn put_object_unknown_length() {
+    let (server, _) = server(0);
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials())
+        .unwrap()
+        .with_part_size(PART_SIZE as u64);
+    let options = PutObjectOptions::new().with_content_type(Mime::from("text/csv"));
+
+    let data = Unsized(Cursor::new(vec![7u8; PART_SIZE + 100]));
+    task::block_on(bucket.put_object_with_options("large.bin", data, &options)).unwrap();
+    let requests = server.requests();
+    assert_eq!(Some("uploads="), requests[0].query());
+    assert_eq!(Some("text/csv"), requests[0].header("content-type"));
+    assert_eq!(vec![PART_SIZE, 100], part_sizes(&server));
+
+    // A payload that fits into a single part is uploaded at once.
+    let data = Unsized(Cursor::new(vec![7u8; 100]));
+    task::block_on(bucket.put_object_with_options("small.bin", data, &options)).unwrap();
+    let request = server.requests().pop().unwrap();
+    assert_eq!(None, request.query());
+    assert_eq!(Some("100"), request.header("content-length"));
+    assert_eq!(Some("text/csv"), request.header("content-type"));
+}
+
+#[test]
+fn put_object_content_md5() {
+    let (server, _) = server(0);
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials())
+        .unwrap()
+        .with_part_size(PART_SIZE as u64);
+    let options = PutObjectOptions::new().with_content_md5(true);
+
+    // A payload of known length is read in parts as well.
+    let data = Cursor::new(vec![7u8; PART_SIZE + 100]);
+    task::block_on(bucket.put_object_with_options("large.bin", data, &options)).unwrap();
+    let parts: Vec<_> = server
+        .requests()
+        .into_iter()
+        .filter(|request| request.method == "PUT")
+        .collect();
+    assert_eq!(2, parts.len());
+    for part in parts {
+        let md5 = Etag::compute_from(&part.body).content_md5();
+        assert_eq!(Some(md5.as_str()), part.header("content-md5"));
+    }
+
+    let data = Cursor::new(vec![7u8; 100]);
+    task::block_on(bucket.put_object_with_options("small.bin", data, &options)).unwrap();
+    let request = server.requests().pop().unwrap();
+    assert_eq!(None, request.query());
+    assert_eq!(
+        Some(Etag::compute_from([7u8; 100]).content_md5().as_str()),
+        request.header("content-md5")
+    );
+
+    let data = Cursor::new(vec![7u8; 100]);
+    task::block_on(bucket.put_object("plain.bin", data)).unwrap();
+    let request = server.requests().pop().unwrap();
+    assert_eq!(None, request.header("content-md5"));
+}
+
+/// Answers multipart upload listings with two pages and
+/// accepts all aborts except that of upload 2.
+fn listing_server() -> Server {
+    Server::start(|request| {
+        match (request.method.as_str(), request.query()) {
+        ("GET", Some(query)) if query.contains("key-marker=") => Response::new(200).body(
+            "<ListMultipartUploadsResult><IsTruncated>false</IsTruncated>\
+             <Upload><Key>logs/b.bin</Key><UploadId>3</UploadId><Initiated>2099-01-01T00:00:00.000Z</Initiated></Upload>\
+             </ListMultipartUploadsResult>",
+        ),
+        ("GET", Some(_)) => Response::new(200).body(
+            "<ListMultipartUploadsResult><IsTruncated>true</IsTruncated>\
+             <NextKeyMarker>logs/a.bin</NextKeyMarker><NextUploadIdMarker>2</NextUploadIdMarker>\
+             <Upload><Key>logs/a.bin</Key><UploadId>1</UploadId><Initiated>2020-01-01T00:00:00.000Z</Initiated></Upload>\
+             <Upload><Key>logs/a.bin</Key><UploadId>2</UploadId><Initiated>2020-01-02T00:00:00.000Z</Initiated></Upload>\
+             <Upload><Key>logs//b.bin</Key><UploadId>4</UploadId><Initiated>2020-01-03T00:00:00.000Z</Initiated></Upload>\
+             </ListMultipartUploadsResult>",
+        ),
+        ("DELETE", Some("uploadId=2")) => Response::error(403, "AccessDenied"),
+        ("DELETE", _) => Response::new(204),
+        _ => Response::new(400),
+    }
+    })
+}
+
+#[test]
+fn list_multipart_uploads() {
+    let server = listing_server();
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let uploads = task::block_on(bucket.list_multipart_uploads("logs/")).unwrap();
+    assert_eq!(
+        vec!["1", "2", "4", "3"],
+        uploads.iter().map(|u| u.upload_id()).collect::<Vec<_>>()
+    );
+    assert_eq!("logs/a.bin", uploads[0].key());
+    assert_eq!(2020, uploads[0].initiated().year());
+    assert_eq!("logs/a.bin", uploads[0].upload().unwrap().key().as_str());
+
+    // Keys are kept as listed, such that handles refer to
+    // the listed upload.
+    assert_eq!("logs//b.bin", uploads[2].key());
+    assert_eq!("logs//b.bin", uploads[2].upload().unwrap().key().as_str());
+
+    let requests = server.requests();
+    assert_eq!(2, requests.len());
+    assert!(requests[0].query().unwrap().contains("prefix=logs%2F"));
+    let second = requests[1].query().unwrap();
+    assert!(second.contains("key-marker=logs%2Fa.bin"));
+    assert!(second.contains("upload-id-marker=2"));
+}
+
+#[test]
+fn abort_incomplete_uploads() {
+    let server = listing_server();
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let result =
+        task::block_on(bucket.abort_incomplete_uploads(Duration::from_secs(3600))).unwrap();
+    assert!(!result.is_success());
+    assert_eq!(
+        vec!["1", "4"],
+        result
+            .aborted()
+            .iter()
+            .map(|u| u.upload_id())
+            .collect::<Vec<_>>()
+    );
+
+    // The failed abort does not stop the remaining uploads from
+    // being aborted.
+    let (failed, err) = &result.errors()[0];
+    assert_eq!(1, result.errors().len());
+    assert_eq!("2", failed.upload_id());
+    assert_eq!(Some(ErrorCode::AccessDenied), err.code());
+
+    let aborts: Vec<_> = server
+        .requests()
+        .into_iter()
+        .filter(|r| r.method == "DELETE")
+        .collect();
+    assert_eq!(3, aborts.len());
+    assert_eq!(
+        "/logs/a.bin",
+        aborts[0].path().trim_start_matches("/my-bucket")
+    );
+    assert_eq!(Some("uploadId=1"), aborts[0].query());
+    assert_eq!(
+        "/logs//b.bin",
+        aborts[2].path().trim_start_matches("/my-bucket")
+    );
+}
+
+#[test]
+fn abort_incomplete_uploads_with_clock() {
+    let server = listing_server();
+    let bucket = Client::new(server.region(), common::credentials())
+        .with_clock(FixedClock::new(datetime!(2020-01-02 12:00 UTC)))
+        .bucket("my-bucket")
+        .unwrap();
+
+    // The age of the uploads is measured with the client's clock.
+    let result =
+        task::block_on(bucket.abort_incomplete_uploads(Duration::from_secs(3600))).unwrap();
+    assert_eq!(
+        vec!["1"],
+        result
+            .aborted()
+            .iter()
+            .map(|u| u.upload_id())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!("2", result.errors()[0].0.upload_id());
+    assert_eq!(1, result.errors().len());
+}
+
+#[test]
+fn abort_no_incomplete_uploads() {
+    let server = listing_server();
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    // No upload is older than the earliest representable date.
+    let result = task::block_on(bucket.abort_incomplete_uploads(Duration::MAX)).unwrap();
+    assert!(result.is_success());
+    assert!(result.aborted().is_empty());
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn compose_object() {
+    let server = Server::start(|request| {
+        match (request.method.as_str(), request.path()) {
+        ("HEAD", "/my-bucket/logs/1") => Response::new(200)
+            .header("ETag", PART_ETAG)
+            .header("Content-Length", 6 << 20),
+        ("HEAD", "/my-bucket/logs/small") => Response::new(200)
+            .header("ETag", PART_ETAG)
+            .header("Content-Length", 1024),
+        ("HEAD", _) => Response::new(200)
+            .header("ETag", OBJECT_ETAG)
+            .header("Content-Length", 1000),
+        ("POST", _) if request.query() == Some("uploads=") => Response::new(200).body(format!(
+            "<InitiateMultipartUploadResult><UploadId>{}</UploadId></InitiateMultipartUploadResult>",
+            UPLOAD_ID
+        )),
+        ("PUT", _) => Response::new(200).body(format!(
+            "<CopyPartResult><LastModified>2022-06-01T12:00:00.000Z</LastModified><ETag>{}</ETag></CopyPartResult>",
+            PART_ETAG.replace('"', "&quot;")
+        )),
+        ("POST", _) => Response::new(200).body(format!(
+            "<CompleteMultipartUploadResult><ETag>{}</ETag></CompleteMultipartUploadResult>",
+            OBJECT_ETAG.replace('"', "&quot;")
+        )),
+        _ => Response::new(400),
+    }
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let sources = [
+        ComposeSource::new(ObjectKey::new("logs/1").unwrap()),
+        ComposeSource::new(ObjectKey::new("logs/2 b").unwrap())
+            .with_bucket(BucketName::new("archive").unwrap())
+            .with_version_id("v1")
+            .with_range(100..200),
+    ];
+    let result = task::block_on(bucket.compose_object(&sources, "logs/all")).unwrap();
+    assert_eq!(Some(&OBJECT_ETAG.parse().unwrap()), result.etag());
+
+    let requests = server.requests();
+    assert_eq!(6, requests.len());
+    assert_eq!("/archive/logs/2%20b", requests[1].path());
+    assert_eq!(Some("versionId=v1"), requests[1].query());
+
+    let first = &requests[3];
+    assert_eq!("PUT", first.method);
+    assert_eq!("/my-bucket/logs/all", first.path());
+    assert!(first.query().unwrap().starts_with("partNumber=1&"));
+    assert_eq!(Some("/my-bucket/logs/1"), first.header("x-amz-copy-source"));
+    assert_eq!(
+        Some("bytes=0-6291455"),
+        first.header("x-amz-copy-source-range")
+    );
+    assert_eq!(Some(PART_ETAG), first.header("x-amz-copy-source-if-match"));
+
+    let second = &requests[4];
+    assert!(second.query().unwrap().starts_with("partNumber=2&"));
+    assert_eq!(
+        Some("/archive/logs/2%20b?versionId=v1"),
+        second.header("x-amz-copy-source")
+    );
+    assert_eq!(
+        Some("bytes=100-199"),
+        second.header("x-amz-copy-source-range")
+    );
+    assert_eq!("POST", requests[5].method);
+
+    // Every source but the last must be at least 5 MiB.
+    let sources = [
+        ComposeSource::new(ObjectKey::new("logs/small").unwrap()),
+        ComposeSource::new(ObjectKey::new("logs/1").unwrap()),
+    ];
+    assert!(task::block_on(bucket.compose_object(&sources, "logs/all")).is_err());
+    assert_eq!(7, server.requests().len());
+
+    // The range must be within the source.
+    let sources = [ComposeSource::new(ObjectKey::new("logs/small").unwrap()).with_range(..2048)];
+    assert!(task::block_on(bucket.compose_object(&sources, "logs/all")).is_err());
+}
@@ -0,0 +1,151 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use futures::StreamExt;
+use minio::s3::{notification::*, Bucket, ErrorCode};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const CONFIGURATION: &str = r#"<NotificationConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+  <QueueConfiguration>
+    <Id>uploads</Id>
+    <Queue>arn:minio:sqs::1:webhook</Queue>
+    <Event>s3:ObjectCreated:*</Event>
+    <Event>s3:ObjectRemoved:Delete</Event>
+    <Filter><S3Key>
+      <FilterRule><Name>prefix</Name><Value>images/</Value></FilterRule>
+      <FilterRule><Name>suffix</Name><Value>.jpg</Value></FilterRule>
+    </S3Key></Filter>
+  </QueueConfiguration>
+  <TopicConfiguration>
+    <Topic>arn:aws:sns:us-east-1:123456789012:events</Topic>
+    <Event>s3:ObjectAccessed:Get</Event>
+  </TopicConfiguration>
+  <QueueConfiguration>
+    <Queue>arn:aws:sqs:us-east-1:123456789012:replication</Queue>
+    <Event>s3:Replication:OperationFailedReplication</Event>
+  </QueueConfiguration>
+</NotificationConfiguration>"#;
+
+#[test]
+fn notification_configuration() {
+    let server = Server::start(|request| match request.method.as_str() {
+        "GET" => Response::new(200).body(CONFIGURATION),
+        _ => Response::new(200),
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let config = task::block_on(bucket.get_notification_configuration()).unwrap();
+    assert_eq!(Some("notification="), server.requests()[0].query());
+    let targets = config.targets();
+    assert_eq!(3, targets.len());
+    assert_eq!(Some("uploads"), targets[0].id());
+    assert_eq!(&Destination::webhook("1"), targets[0].destination());
+    assert_eq!(
+        &[EventType::ObjectCreated, EventType::ObjectRemovedDelete],
+        targets[0].events()
+    );
+    assert_eq!(Some("images/"), targets[0].prefix());
+    assert_eq!(Some(".jpg"), targets[0].suffix());
+    assert_eq!(
+        "arn:aws:sns:us-east-1:123456789012:events",
+        targets[1].destination().arn()
+    );
+    assert!(matches!(targets[1].destination(), Destination::Topic(_)));
+    assert_eq!(None, targets[1].prefix());
+    assert_eq!(
+        "s3:Replication:OperationFailedReplication",
+        targets[2].events()[0].as_str()
+    );
+
+    task::block_on(bucket.put_notification_configuration(&config)).unwrap();
+    let request = &server.requests()[1];
+    assert_eq!("PUT", request.method);
+    assert_eq!(Some("notification="), request.query());
+    let body = String::from_utf8(request.body.clone()).unwrap();
+    assert!(body.contains(
+        "<QueueConfiguration><Id>uploads</Id><Queue>arn:minio:sqs::1:webhook</Queue>\
+         <Event>s3:ObjectCreated:*</Event><Event>s3:ObjectRemoved:Delete</Event>\
+         <Filter><S3Key><FilterRule><Name>prefix</Name><Value>images/</Value></FilterRule>\
+         <FilterRule><Name>suffix</Name><Value>.jpg</Value></FilterRule></S3Key></Filter>\
+         </QueueConfiguration>"
+    ));
+    assert!(body.contains("<TopicConfiguration><Topic>"));
+}
+
+#[test]
+fn empty_notification_configuration() {
+    let server = Server::start(|_| {
+        Response::new(200)
+            .body(r#"<NotificationConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/"/>"#)
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let config = task::block_on(bucket.get_notification_configuration()).unwrap();
+    assert!(config.is_empty());
+}
+
+const RECORD: &str = r#"{"EventName":"s3:ObjectCreated:Put","Key":"my-bucket/images/a b.jpg","Records":[{"eventVersion":"2.0","eventSource":"minio:s3","awsRegion":"","eventTime":"2022-06-01T12:00:00.000Z","eventName":"s3:ObjectCreated:Put","userIdentity":{"principalId":"minio"},"s3":{"s3SchemaVersion":"1.0","configurationId":"Config","bucket":{"name":"my-bucket","ownerIdentity":{"principalId":"minio"},"arn":"arn:aws:s3:::my-bucket"},"object":{"key":"images%2Fa+b.jpg","size":1024,"eTag":"d41d8cd98f00b204e9800998ecf8427e","contentType":"image/jpeg","userMetadata":{"content-type":"image/jpeg"},"sequencer":"16F4E7D2B7A8F3C1"}}}]}"#;
+
+#[test]
+fn listen() {
+    let connections = AtomicUsize::new(0);
+    let server = Server::start(move |_| match connections.fetch_add(1, Ordering::SeqCst) {
+        0 => Response::new(200).body(format!(" \n{}\n \n", RECORD)),
+        1 => Response::new(200).body(format!(
+            "{}\n",
+            RECORD.replace("ObjectCreated:Put", "ObjectRemoved:Delete")
+        )),
+        _ => Response::error(404, "NoSuchBucket"),
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let events = [EventType::ObjectCreated, EventType::ObjectRemoved];
+    let records: Vec<_> =
+        task::block_on(Box::pin(bucket.listen("images/", ".jpg", &events)).collect());
+    assert_eq!(3, records.len());
+
+    let record = records[0].as_ref().unwrap();
+    assert_eq!(&EventType::ObjectCreatedPut, record.event_type());
+    assert_eq!(1654084800, record.event_time().unix_timestamp());
+    assert_eq!("my-bucket", record.bucket());
+    assert_eq!("images/a b.jpg", record.key());
+    assert_eq!(Some(1024), record.size());
+    assert_eq!(
+        "d41d8cd98f00b204e9800998ecf8427e",
+        record.etag().unwrap().to_string()
+    );
+    assert_eq!(Some("image/jpeg"), record.content_type());
+    assert_eq!(Some("minio"), record.principal_id());
+    assert_eq!(None, record.version_id());
+
+    let record = records[1].as_ref().unwrap();
+    assert_eq!(&EventType::ObjectRemovedDelete, record.event_type());
+    assert_eq!(
+        Some(ErrorCode::NoSuchBucket),
+        records[2].as_ref().unwrap_err().code()
+    );
+
+    let requests = server.requests();
+    assert_eq!(3, requests.len());
+    assert_eq!("/my-bucket/", requests[0].path());
+    assert_eq!(
+        Some("prefix=images%2F&suffix=.jpg&events=s3%3AObjectCreated%3A%2A&events=s3%3AObjectRemoved%3A%2A"),
+        requests[0].query()
+    );
+}
@@ -0,0 +1,377 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::{
+    io::{Cursor, ReadExt},
+    task,
+};
+use common::{Response, Server};
+use minio::s3::{
+    acl::CannedAcl, object::Content, Bucket, ChecksumAlgorithm, ContentRange, ErrorCode, Etag,
+    GetObjectOptions, GetObjectOutcome, IntegrityError, PutObjectOptions, RestoreStatus,
+    RestoreTier, StorageClass,
+};
+use time::macros::datetime;
+
+#[test]
+fn stat_object() {
+    let server = Server::start(|request| match request.path() {
+        "/my-bucket/photos/cat.jpg" => Response::new(200)
+            .header("ETag", "\"b10a8db164e0754105b7a99be72e3fe5\"")
+            .header("Content-Length", 1024)
+            .header("x-amz-storage-class", "GLACIER"),
+        _ => Response::new(404),
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let metadata = task::block_on(bucket.stat_object("photos/cat.jpg")).unwrap();
+    assert_eq!(1024, metadata.size());
+    assert_eq!(StorageClass::Glacier, metadata.storage_class());
+    assert_eq!("HEAD", server.requests()[0].method);
+
+    let err = task::block_on(bucket.stat_object("photos/dog.jpg")).unwrap_err();
+    assert!(err.is_not_found());
+    assert_eq!(Some(ErrorCode::NoSuchKey), err.code());
+}
+
+#[test]
+fn storage_classes() {
+    let server = Server::start(|request| {
+        let class = request.path().trim_start_matches("/my-bucket/");
+        Response::new(200)
+            .header("ETag", "\"b10a8db164e0754105b7a99be72e3fe5\"")
+            .header("Content-Length", 1024)
+            .header("x-amz-storage-class", class)
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    for class in [
+        StorageClass::StandardIa,
+        StorageClass::OnezoneIa,
+        StorageClass::GlacierIr,
+        StorageClass::IntelligentTiering,
+        StorageClass::DeepArchive,
+        StorageClass::Outposts,
+        StorageClass::Other(String::from("COLD")),
+    ] {
+        let metadata = task::block_on(bucket.stat_object(class.as_str())).unwrap();
+        assert_eq!(class, metadata.storage_class());
+    }
+}
+
+#[test]
+fn get_object_range() {
+    const DATA: &[u8] = b"0123456789abcdefghij";
+    let server = Server::start(|request| {
+        let range = request
+            .header("range")
+            .and_then(|range| range.strip_prefix("bytes="))
+            .and_then(|range| range.split_once('-'));
+        let (start, end) = match range {
+            Some((start, end)) => (
+                start.parse::<usize>().unwrap(),
+                end.parse::<usize>()
+                    .map_or(DATA.len() - 1, |end| end.min(DATA.len() - 1)),
+            ),
+            None => return Response::new(400),
+        };
+        Response::new(206)
+            .header("ETag", "\"b10a8db164e0754105b7a99be72e3fe5\"")
+            .header(
+                "Content-Range",
+                format!("bytes {}-{}/{}", start, end, DATA.len()),
+            )
+            .body(&DATA[start..=end])
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let read = |object: minio::s3::Object| {
+        let mut content = Vec::new();
+        task::block_on(Content::from(object).read_to_end(&mut content)).unwrap();
+        content
+    };
+
+    let object = task::block_on(bucket.get_object_range("data.txt", 2..5)).unwrap();
+    assert_eq!(Some("bytes=2-4"), server.requests()[0].header("range"));
+    let range = *object.content_range().unwrap();
+    assert_eq!(
+        (2, 4, 3, 20),
+        (range.start(), range.end(), range.len(), range.size())
+    );
+    assert_eq!(20, object.metadata().size());
+    assert_eq!(b"234".to_vec(), read(object));
+
+    let object = task::block_on(bucket.get_object_range("data.txt", 15..)).unwrap();
+    assert_eq!(Some("bytes=15-"), server.requests()[1].header("range"));
+    assert_eq!(b"fghij".to_vec(), read(object));
+
+    let object = task::block_on(bucket.get_object_range("data.txt", ..=0)).unwrap();
+    assert_eq!(b"0".to_vec(), read(object));
+
+    assert!(task::block_on(bucket.get_object_range("data.txt", 5..5)).is_err());
+    assert_eq!(3, server.requests().len());
+}
+
+#[test]
+fn content_range() {
+    let range: ContentRange = "bytes 0-0/1".parse().unwrap();
+    assert_eq!(1, range.len());
+    assert_eq!("bytes 0-0/1", range.to_string());
+
+    assert!("bytes 5-4/10".parse::<ContentRange>().is_err());
+    assert!("bytes 0-10/10".parse::<ContentRange>().is_err());
+    assert!("bytes */10".parse::<ContentRange>().is_err());
+}
+
+#[test]
+fn get_object_with_options() {
+    const ETAG: &str = "\"b10a8db164e0754105b7a99be72e3fe5\"";
+    let server = Server::start(|request| {
+        if request.header("if-none-match") == Some(ETAG) {
+            return Response::new(304);
+        }
+        if request.header("if-match").is_some_and(|etag| etag != ETAG) {
+            return Response::error(412, "PreconditionFailed");
+        }
+        Response::new(200).header("ETag", ETAG).body("data")
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+    let etag: Etag = ETAG.parse().unwrap();
+    let other: Etag = "\"3858f62230ac3c915f300c664312c11f\"".parse().unwrap();
+
+    let options = GetObjectOptions::new().with_if_none_match(etag);
+    let outcome = task::block_on(bucket.get_object_with_options("data.txt", &options)).unwrap();
+    assert!(matches!(outcome, GetObjectOutcome::NotModified));
+    assert_eq!(Some(ETAG), server.requests()[0].header("if-none-match"));
+
+    let options = GetObjectOptions::new().with_if_match(other);
+    let outcome = task::block_on(bucket.get_object_with_options("data.txt", &options)).unwrap();
+    assert!(matches!(outcome, GetObjectOutcome::PreconditionFailed));
+
+    let options = GetObjectOptions::new()
+        .with_if_none_match(other)
+        .with_if_modified_since(datetime!(1994-11-06 8:49:37 UTC));
+    let outcome = task::block_on(bucket.get_object_with_options("data.txt", &options)).unwrap();
+    assert!(matches!(outcome, GetObjectOutcome::Object(_)));
+    assert_eq!(
+        Some("Sun, 06 Nov 1994 08:49:37 GMT"),
+        server.requests()[2].header("if-modified-since")
+    );
+}
+
+#[test]
+fn get_object_verify_etag() {
+    const ETAG: &str = "\"b10a8db164e0754105b7a99be72e3fe5\"";
+    let server = Server::start(|request| match request.path() {
+        "/my-bucket/valid.txt" => Response::new(200).header("ETag", ETAG).body("Hello World"),
+        "/my-bucket/multipart.txt" => Response::new(200)
+            .header("ETag", "\"b10a8db164e0754105b7a99be72e3fe5-2\"")
+            .body("corrupted"),
+        _ => Response::new(200).header("ETag", ETAG).body("Hello Worlt"),
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+    let options = GetObjectOptions::new().with_verify_etag(true);
+    let read = |key: &'static str| {
+        task::block_on(async {
+            let outcome = bucket.get_object_with_options(key, &options).await.unwrap();
+            let GetObjectOutcome::Object(mut object) = outcome else {
+                panic!("object expected");
+            };
+            let mut data = Vec::new();
+            object
+                .content_mut()
+                .read_to_end(&mut data)
+                .await
+                .map(|_| data)
+        })
+    };
+
+    assert_eq!(b"Hello World", read("valid.txt").unwrap().as_slice());
+    assert_eq!(b"corrupted", read("multipart.txt").unwrap().as_slice());
+
+    let err = read("corrupted.txt").unwrap_err();
+    assert_eq!(std::io::ErrorKind::InvalidData, err.kind());
+    let err = err
+        .get_ref()
+        .and_then(|err| err.downcast_ref::<IntegrityError>())
+        .unwrap();
+    assert_eq!(ETAG.parse::<Etag>().unwrap(), *err.expected());
+    assert_eq!(Etag::compute_from("Hello Worlt"), *err.actual());
+}
+
+#[test]
+fn put_object_with_options() {
+    let server = Server::start(|_| {
+        Response::new(200).header("ETag", "\"b10a8db164e0754105b7a99be72e3fe5\"")
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let options = PutObjectOptions::new()
+        .with_content_type("text/html".parse().unwrap())
+        .with_cache_control("max-age=3600")
+        .with_content_disposition("inline")
+        .with_content_encoding("gzip")
+        .with_content_language("en-US")
+        .with_acl(CannedAcl::PublicRead);
+    task::block_on(bucket.put_object_with_options("index.html", Cursor::new("<html>"), &options))
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(Some("text/html"), request.header("content-type"));
+    assert_eq!(Some("max-age=3600"), request.header("cache-control"));
+    assert_eq!(Some("inline"), request.header("content-disposition"));
+    assert_eq!(Some("gzip"), request.header("content-encoding"));
+    assert_eq!(Some("en-US"), request.header("content-language"));
+    assert_eq!(Some("public-read"), request.header("x-amz-acl"));
+    let signed = request.header("authorization").unwrap();
+    assert!(signed.contains(
+        "SignedHeaders=cache-control;content-disposition;content-encoding;content-language;content-type;"
+    ));
+}
+
+#[test]
+fn put_object_with_checksum() {
+    let server = Server::start(|_| {
+        Response::new(200)
+            .header("ETag", "\"b10a8db164e0754105b7a99be72e3fe5\"")
+            .header("x-amz-checksum-crc32c", "aR2qLw==")
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let options = PutObjectOptions::new()
+        .with_content_encoding("gzip")
+        .with_checksum(ChecksumAlgorithm::Crc32c);
+    let data = Cursor::new("Hello World");
+    let result =
+        task::block_on(bucket.put_object_with_options("hello.txt", data, &options)).unwrap();
+    assert_eq!(Some("aR2qLw=="), result.checksum(ChecksumAlgorithm::Crc32c));
+
+    let request = &server.requests()[0];
+    assert_eq!(Some("aws-chunked,gzip"), request.header("content-encoding"));
+    assert_eq!(
+        Some("x-amz-checksum-crc32c"),
+        request.header("x-amz-trailer")
+    );
+    assert_eq!(
+        b"b\r\nHello World\r\n0\r\nx-amz-checksum-crc32c:aR2qLw==\r\n\r\n",
+        request.body.as_slice()
+    );
+}
+
+#[test]
+fn user_metadata() {
+    let server = Server::start(|request| match request.method.as_str() {
+        "PUT" => Response::new(200).header("ETag", "\"b10a8db164e0754105b7a99be72e3fe5\""),
+        _ => Response::new(200)
+            .header("ETag", "\"b10a8db164e0754105b7a99be72e3fe5\"")
+            .header("Content-Length", 4)
+            .header("X-Amz-Meta-Project", "apollo")
+            .header("x-amz-meta-build-id", "42"),
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let options = PutObjectOptions::new()
+        .with_metadata("Project", "apollo")
+        .with_metadata("build-id", "42");
+    assert_eq!(
+        vec![("build-id", "42"), ("project", "apollo")],
+        options.user_metadata().collect::<Vec<_>>()
+    );
+    task::block_on(bucket.put_object_with_options("data.bin", Cursor::new("data"), &options))
+        .unwrap();
+    let request = &server.requests()[0];
+    assert_eq!(Some("apollo"), request.header("x-amz-meta-project"));
+    assert_eq!(Some("42"), request.header("x-amz-meta-build-id"));
+
+    let metadata = task::block_on(bucket.stat_object("data.bin")).unwrap();
+    assert_eq!(2, metadata.user_metadata().len());
+    assert_eq!("apollo", metadata.user_metadata()["project"]);
+    assert_eq!("42", metadata.user_metadata()["build-id"]);
+}
+
+#[test]
+fn object_versions() {
+    let server = Server::start(|request| {
+        let version = match request.query() {
+            Some(query) => query.trim_start_matches("versionId="),
+            None => "v2",
+        };
+        match request.method.as_str() {
+            "DELETE" => Response::new(204).header("x-amz-version-id", version),
+            _ => Response::new(200)
+                .header("ETag", "\"b10a8db164e0754105b7a99be72e3fe5\"")
+                .header("x-amz-version-id", version)
+                .body("data"),
+        }
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let metadata = task::block_on(bucket.stat_object("data.bin")).unwrap();
+    assert_eq!(Some("v2"), metadata.version_id());
+    assert_eq!(None, server.requests()[0].query());
+
+    let metadata = task::block_on(bucket.stat_object_version("data.bin", "v1")).unwrap();
+    assert_eq!(Some("v1"), metadata.version_id());
+
+    let object = task::block_on(bucket.get_object_version("data.bin", "v1")).unwrap();
+    assert_eq!(Some("v1"), object.metadata().version_id());
+    assert_eq!("GET", server.requests()[2].method);
+    assert_eq!(Some("versionId=v1"), server.requests()[2].query());
+
+    let result = task::block_on(bucket.delete_object_version("data.bin", "v1")).unwrap();
+    assert_eq!(Some("v1"), result.version_id());
+    assert_eq!(Some("versionId=v1"), server.requests()[3].query());
+}
+
+#[test]
+fn restore_object() {
+    let server = Server::start(|request| match (request.method.as_str(), request.path()) {
+        ("POST", _) => Response::new(202),
+        (_, "/my-bucket/pending.tar") => Response::new(200)
+            .header("ETag", "\"b10a8db164e0754105b7a99be72e3fe5\"")
+            .header("x-amz-storage-class", "GLACIER")
+            .header("x-amz-restore", "ongoing-request=\"true\""),
+        _ => Response::new(200)
+            .header("ETag", "\"b10a8db164e0754105b7a99be72e3fe5\"")
+            .header("x-amz-storage-class", "DEEP_ARCHIVE")
+            .header(
+                "x-amz-restore",
+                "ongoing-request=\"false\", expiry-date=\"Fri, 21 Dec 2012 00:00:00 GMT\"",
+            ),
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    task::block_on(bucket.restore_object("archive.tar", 7, RestoreTier::Bulk)).unwrap();
+    let request = &server.requests()[0];
+    assert_eq!("POST", request.method);
+    assert_eq!(Some("restore="), request.query());
+    assert_eq!(
+        r#"<RestoreRequest xmlns="http://s3.amazonaws.com/doc/2006-03-01/"><Days>7</Days><GlacierJobParameters><Tier>Bulk</Tier></GlacierJobParameters></RestoreRequest>"#.as_bytes(),
+        request.body.as_slice()
+    );
+
+    let metadata = task::block_on(bucket.stat_object("pending.tar")).unwrap();
+    assert_eq!(Some(RestoreStatus::InProgress), metadata.restore());
+
+    let metadata = task::block_on(bucket.stat_object("archive.tar")).unwrap();
+    assert_eq!(
+        Some(RestoreStatus::Restored {
+            expiry: datetime!(2012-12-21 00:00:00 UTC)
+        }),
+        metadata.restore()
+    );
+}
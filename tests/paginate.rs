@@ -0,0 +1,76 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_std::task;
+use futures::{StreamExt, TryStreamExt};
+use minio::s3::{
+    paginate::{Page, Paginator},
+    ObjectKey,
+};
+
+struct Numbers {
+    numbers: Vec<u32>,
+    next: Option<String>,
+}
+
+impl Page for Numbers {
+    type Item = u32;
+
+    fn next_token(&self) -> Option<&str> {
+        self.next.as_deref()
+    }
+
+    fn into_items(self) -> Vec<u32> {
+        self.numbers
+    }
+}
+
+// Returns three pages: [0, 1], [2, 3] and [4].
+async fn fetch(token: Option<String>) -> minio::s3::Result<Numbers> {
+    let start: u32 = token.map_or(0, |token| token.parse().unwrap());
+    let end = (start + 2).min(5);
+    Ok(Numbers {
+        numbers: (start..end).collect(),
+        next: match end {
+            5 => None,
+            end => Some(end.to_string()),
+        },
+    })
+}
+
+#[test]
+fn pages() {
+    let pages: Vec<Numbers> = task::block_on(Paginator::new(fetch).pages().try_collect()).unwrap();
+    assert_eq!(3, pages.len());
+    assert_eq!(Some("2"), pages[0].next_token());
+    assert_eq!(None, pages[2].next_token());
+
+    let items: Vec<u32> = task::block_on(Paginator::new(fetch).items().try_collect()).unwrap();
+    assert_eq!(vec![0, 1, 2, 3, 4], items);
+}
+
+#[test]
+fn stop_on_error() {
+    let pages = Paginator::new(|token: Option<String>| async move {
+        match token {
+            None => fetch(None).await,
+            Some(_) => Err("".parse::<ObjectKey>().unwrap_err().into()),
+        }
+    });
+    let results: Vec<_> = task::block_on(pages.pages().collect());
+    assert_eq!(2, results.len());
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+}
@@ -0,0 +1,100 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::{payload, payload::SizedFile, Bucket, ChecksumAlgorithm, Etag, Payload};
+
+#[test]
+fn put_sized_file() {
+    let path = std::env::temp_dir().join(format!("minio-payload-{}", std::process::id()));
+    std::fs::write(&path, b"Hello World").unwrap();
+
+    let server = Server::start(|_| Response::new(200));
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let file = task::block_on(SizedFile::open(&path)).unwrap();
+    assert_eq!(Some(11), Payload::len(&file));
+    let result = task::block_on(bucket.put_object("hello.txt", file));
+    std::fs::remove_file(&path).unwrap();
+    result.unwrap();
+
+    let requests = server.requests();
+    assert_eq!("/my-bucket/hello.txt", requests[0].path());
+    assert_eq!(Some("11"), requests[0].header("content-length"));
+    assert_eq!(b"Hello World", requests[0].body.as_slice());
+}
+
+#[test]
+fn put_reader() {
+    let server = Server::start(|_| Response::new(200));
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    // The payload is cut off after the given length.
+    let reader = async_std::io::Cursor::new(b"Hello World".to_vec());
+    let data = payload::from_reader(reader, 5);
+    assert_eq!(Some(5), Payload::len(&data));
+    task::block_on(bucket.put_object("hello.txt", data)).unwrap();
+
+    let requests = server.requests();
+    assert_eq!(Some("5"), requests[0].header("content-length"));
+    assert_eq!(b"Hello", requests[0].body.as_slice());
+}
+
+#[test]
+fn short_reader() {
+    use async_std::io::ReadExt;
+
+    let reader = async_std::io::Cursor::new(b"Hello".to_vec());
+    let mut data = payload::from_reader(reader, 11);
+    let mut buf = Vec::new();
+    let err = task::block_on(data.read_to_end(&mut buf)).unwrap_err();
+    assert_eq!(std::io::ErrorKind::UnexpectedEof, err.kind());
+    assert_eq!(b"Hello", buf.as_slice());
+}
+
+#[test]
+fn put_object_result() {
+    let server = Server::start(|_| {
+        Response::new(200)
+            .header("ETag", "\"b10a8db164e0754105b7a99be72e3fe5\"")
+            .header("x-amz-version-id", "3HL4kqtJlcpXroDTDmJ+rmSpXd3dIbrHY")
+            .header("x-amz-server-side-encryption", "aws:kms")
+            .header("x-amz-server-side-encryption-aws-kms-key-id", "my-key")
+            .header("x-amz-checksum-crc32c", "yZRlqg==")
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let result = task::block_on(bucket.put_object_bytes("hello.txt", "Hello World")).unwrap();
+    let etag: Etag = "b10a8db164e0754105b7a99be72e3fe5".parse().unwrap();
+    assert_eq!(Some(&etag), result.etag());
+    assert_eq!(
+        Some("3HL4kqtJlcpXroDTDmJ+rmSpXd3dIbrHY"),
+        result.version_id()
+    );
+    assert_eq!(Some("aws:kms"), result.server_side_encryption());
+    assert_eq!(Some("my-key"), result.kms_key_id());
+    assert_eq!(Some("yZRlqg=="), result.checksum(ChecksumAlgorithm::Crc32c));
+    assert_eq!(None, result.checksum(ChecksumAlgorithm::Sha256));
+
+    // Servers may omit all of it.
+    let server = Server::start(|_| Response::new(200));
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+    let result = task::block_on(bucket.put_object_bytes("hello.txt", "Hello World")).unwrap();
+    assert_eq!(None, result.etag());
+    assert_eq!(None, result.version_id());
+}
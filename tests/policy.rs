@@ -0,0 +1,50 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::Bucket;
+
+const POLICY: &str = r#"{"Version":"2012-10-17","Statement":[{"Effect":"Allow","Principal":"*","Action":["s3:GetObject"],"Resource":["arn:aws:s3:::my-bucket/*"]}]}"#;
+
+#[test]
+fn bucket_policy() {
+    let server = Server::start(|request| match (request.method.as_str(), request.path()) {
+        ("GET", "/my-bucket/") => Response::new(200).body(POLICY),
+        ("GET", _) => Response::error(404, "NoSuchBucketPolicy"),
+        _ => Response::new(204),
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    task::block_on(bucket.set_policy(POLICY)).unwrap();
+    let request = &server.requests()[0];
+    assert_eq!("PUT", request.method);
+    assert_eq!(Some("policy="), request.query());
+    assert_eq!(Some("application/json"), request.header("content-type"));
+    assert_eq!(POLICY.as_bytes(), request.body.as_slice());
+
+    assert_eq!(
+        Some(POLICY),
+        task::block_on(bucket.get_policy()).unwrap().as_deref()
+    );
+
+    task::block_on(bucket.delete_policy()).unwrap();
+    assert_eq!("DELETE", server.requests()[2].method);
+
+    let bucket = Bucket::new("private", server.region(), common::credentials()).unwrap();
+    assert_eq!(None, task::block_on(bucket.get_policy()).unwrap());
+}
@@ -0,0 +1,62 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::{pool::ConnectionPool, Bucket, Client};
+use std::time::Duration;
+
+#[test]
+fn shared_pool() {
+    let server = Server::start(|_| Response::new(200));
+    let client = Client::new(server.region(), common::credentials());
+    assert!(client.connection_pool().ptr_eq(&ConnectionPool::shared()));
+
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+    assert!(bucket
+        .client()
+        .connection_pool()
+        .ptr_eq(&ConnectionPool::shared()));
+}
+
+#[test]
+fn dedicated_pool() {
+    let server = Server::start(|_| Response::new(200));
+    let pool = ConnectionPool::new()
+        .with_max_connections(4)
+        .with_max_connections_per_host(2)
+        .with_max_idle_connections(2)
+        .with_idle_timeout(Duration::from_secs(30));
+    assert_eq!(Some(4), pool.max_connections());
+    assert_eq!(Some(2), pool.max_connections_per_host());
+    assert_eq!(Some(2), pool.max_idle_connections());
+    assert_eq!(Some(Duration::from_secs(30)), pool.idle_timeout());
+    assert!(!pool.ptr_eq(&ConnectionPool::shared()));
+
+    let client =
+        Client::new(server.region(), common::credentials()).with_connection_pool(pool.clone());
+    let first = client.bucket("first").unwrap();
+    let second = client.bucket("second").unwrap();
+    assert!(first.client().connection_pool().ptr_eq(&pool));
+    assert!(second.client().connection_pool().ptr_eq(&pool));
+
+    assert!(task::block_on(first.exists()).unwrap());
+    assert!(task::block_on(second.exists()).unwrap());
+    let requests = server.requests();
+    assert_eq!("/first/", requests[0].path());
+    assert_eq!("/second/", requests[1].path());
+}
@@ -0,0 +1,93 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::{proxy::Proxy, Bucket, Region};
+use std::env;
+
+#[test]
+fn explicit_proxy() {
+    let proxy = Server::start(|_| Response::new(200));
+    let region = Region::custom("http://s3.invalid:9000").unwrap();
+    let bucket = Bucket::new("my-bucket", region, common::credentials())
+        .unwrap()
+        .with_proxy(
+            Proxy::new(proxy.endpoint())
+                .unwrap()
+                .with_credentials("alice", "secret"),
+        );
+
+    assert!(task::block_on(bucket.exists()).unwrap());
+    let request = &proxy.requests()[0];
+    assert_eq!("http://s3.invalid:9000/my-bucket/", request.target);
+    assert_eq!(
+        Some("Basic YWxpY2U6c2VjcmV0"),
+        request.header("proxy-authorization")
+    );
+}
+
+#[test]
+fn no_proxy() {
+    let server = Server::start(|_| Response::new(200));
+    let proxy = Proxy::new("http://127.0.0.1:1")
+        .unwrap()
+        .with_no_proxy("127.0.0.1");
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials())
+        .unwrap()
+        .with_proxy(proxy);
+
+    assert!(task::block_on(bucket.exists()).unwrap());
+    assert_eq!("/my-bucket/", server.requests()[0].target);
+}
+
+#[test]
+fn proxy_from_env() {
+    let proxy = Server::start(|_| Response::new(200));
+    for name in [
+        "http_proxy",
+        "https_proxy",
+        "HTTPS_PROXY",
+        "all_proxy",
+        "ALL_PROXY",
+        "no_proxy",
+    ] {
+        env::remove_var(name);
+    }
+    env::set_var("HTTP_PROXY", proxy.endpoint());
+    env::set_var("NO_PROXY", "direct.invalid, .internal.invalid");
+
+    let region = Region::custom("http://s3.invalid:9000").unwrap();
+    let bucket = Bucket::new("my-bucket", region, common::credentials()).unwrap();
+    assert!(task::block_on(bucket.exists()).unwrap());
+    assert_eq!(
+        "http://s3.invalid:9000/my-bucket/",
+        proxy.requests()[0].target
+    );
+
+    let url = "http://minio.internal.invalid".parse().unwrap();
+    assert!(Proxy::from_env(&url).is_none());
+    let url = "https://s3.invalid".parse().unwrap();
+    assert!(Proxy::from_env(&url).is_none());
+
+    let region = Region::custom("http://s3.invalid:9000").unwrap();
+    let bucket = Bucket::new("my-bucket", region, common::credentials())
+        .unwrap()
+        .with_proxy(Proxy::none());
+    assert!(task::block_on(bucket.exists()).is_err());
+    assert_eq!(1, proxy.requests().len());
+}
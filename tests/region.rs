@@ -13,7 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use minio::s3::Region;
+use minio::s3::{region::Partition, Region};
 
 #[test]
 fn custom_endpoint() {
@@ -53,3 +53,101 @@ fn custom_host() {
         assert_eq!(test.0, test.1.expect("").host());
     }
 }
+
+#[test]
+fn custom_endpoints() {
+    let region =
+        Region::custom_with_endpoints(["http://127.0.0.1:9000", "127.0.0.1:9001"], "us-east-1")
+            .unwrap();
+    assert_eq!("http://127.0.0.1:9000", region.endpoint());
+    assert_eq!(
+        vec!["http://127.0.0.1:9000", "127.0.0.1:9001"],
+        region.endpoints()
+    );
+    assert_eq!("us-east-1", region.to_string());
+
+    let endpoints: [&str; 0] = [];
+    assert!(Region::custom_with_endpoints(endpoints, "us-east-1").is_err());
+    assert!(Region::custom_with_endpoints(["localhost", ""], "us-east-1").is_err());
+}
+
+#[test]
+fn partitions() {
+    let regions = vec![
+        ("us-east-1", "https://s3.amazonaws.com", Partition::Aws),
+        (
+            "cn-north-1",
+            "https://s3.cn-north-1.amazonaws.com.cn",
+            Partition::AwsCn,
+        ),
+        (
+            "cn-northwest-1",
+            "https://s3.cn-northwest-1.amazonaws.com.cn",
+            Partition::AwsCn,
+        ),
+        (
+            "us-gov-west-1",
+            "https://s3.us-gov-west-1.amazonaws.com",
+            Partition::AwsUsGov,
+        ),
+    ];
+    for (name, endpoint, partition) in regions {
+        let region: Region = name.parse().unwrap();
+        assert_eq!(endpoint, region.endpoint());
+        assert_eq!(Some(partition), region.partition());
+        assert_eq!(name, region.to_string());
+    }
+    assert_eq!(Partition::AwsCn, Partition::of("cn-north-1"));
+    assert_eq!("aws-us-gov", Partition::AwsUsGov.as_str());
+}
+
+#[test]
+fn aws_regions() {
+    let names = [
+        "af-south-1",
+        "ap-east-1",
+        "ap-east-2",
+        "ap-northeast-1",
+        "ap-northeast-2",
+        "ap-northeast-3",
+        "ap-south-1",
+        "ap-south-2",
+        "ap-southeast-1",
+        "ap-southeast-2",
+        "ap-southeast-3",
+        "ap-southeast-4",
+        "ap-southeast-5",
+        "ap-southeast-6",
+        "ap-southeast-7",
+        "ca-central-1",
+        "ca-west-1",
+        "eu-central-1",
+        "eu-central-2",
+        "eu-north-1",
+        "eu-south-1",
+        "eu-south-2",
+        "eu-west-1",
+        "eu-west-2",
+        "eu-west-3",
+        "il-central-1",
+        "me-central-1",
+        "me-south-1",
+        "mx-central-1",
+        "sa-east-1",
+    ];
+    for name in names {
+        let region: Region = name.parse().unwrap();
+        assert!(!matches!(region, Region::Custom { .. }), "{}", name);
+        assert_eq!(name, region.to_string());
+        assert_eq!(
+            format!("https://s3.{}.amazonaws.com", name),
+            region.endpoint()
+        );
+        assert_eq!(Some(Partition::Aws), region.partition());
+    }
+    assert_eq!(Region::EuCentral1, "eu-central-1".parse().unwrap());
+    assert_eq!(
+        "s3.ap-southeast-2.amazonaws.com",
+        Region::ApSoutheast2.host()
+    );
+}
@@ -0,0 +1,110 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use minio::s3::{
+    transport::{async_trait, Transport},
+    AddressingStyle, Client, Region,
+};
+use std::sync::{Arc, Mutex};
+use surf::http::{Request, Response, Result, StatusCode};
+
+/// A transport that serves a bucket in `eu-west-1` and
+/// rejects requests to other regions.
+#[derive(Debug, Default)]
+struct Mock {
+    requests: Mutex<Vec<(String, String)>>,
+}
+
+#[async_trait]
+impl Transport for Mock {
+    async fn send(&self, request: Request) -> Result<Response> {
+        let url = request.url().to_string();
+        let authorization = request["Authorization"].as_str().to_owned();
+        self.requests
+            .lock()
+            .unwrap()
+            .push((url.clone(), authorization));
+        if url.contains("eu-west-1") {
+            let mut response = Response::new(StatusCode::Ok);
+            response.insert_header("ETag", "\"8d777f385d3dfec8815d20f7496026dc\"");
+            return Ok(response);
+        }
+        let mut response = Response::new(StatusCode::BadRequest);
+        response.set_body(
+            "<Error><Code>AuthorizationHeaderMalformed</Code>\
+             <Message>the region 'us-east-1' is wrong; expecting 'eu-west-1'</Message>\
+             <Region>eu-west-1</Region></Error>",
+        );
+        Ok(response)
+    }
+}
+
+#[test]
+fn discover_bucket_region() {
+    let mock = Arc::new(Mock::default());
+    let client = Client::new(Region::UsEast1, common::credentials()).with_transport(mock.clone());
+    assert_eq!(None, client.bucket_region("my-bucket"));
+
+    let bucket = client.bucket("my-bucket").unwrap();
+    task::block_on(bucket.put_object_bytes("data.bin", b"data")).unwrap();
+    assert_eq!(Some(Region::EuWest1), client.bucket_region("my-bucket"));
+    task::block_on(bucket.put_object_bytes("data.bin", b"data")).unwrap();
+
+    let requests = mock.requests.lock().unwrap();
+    assert_eq!(3, requests.len());
+    assert_eq!("https://s3.amazonaws.com/my-bucket/data.bin", requests[0].0);
+    assert!(requests[0].1.contains("/us-east-1/s3/aws4_request"));
+    for (url, authorization) in &requests[1..] {
+        assert_eq!("https://s3.eu-west-1.amazonaws.com/my-bucket/data.bin", url);
+        assert!(authorization.contains("/eu-west-1/s3/aws4_request"));
+    }
+}
+
+#[test]
+fn discover_bucket_region_virtual_hosted() {
+    let mock = Arc::new(Mock::default());
+    let client = Client::new(Region::UsEast1, common::credentials())
+        .with_transport(mock.clone())
+        .with_addressing_style(AddressingStyle::VirtualHosted);
+
+    let bucket = client.bucket("my-bucket").unwrap();
+    task::block_on(bucket.put_object_bytes("data.bin", b"data")).unwrap();
+    assert_eq!(
+        Some(Region::EuWest1),
+        client.clone().bucket_region("my-bucket")
+    );
+
+    let requests = mock.requests.lock().unwrap();
+    assert_eq!("https://my-bucket.s3.amazonaws.com/data.bin", requests[0].0);
+    assert_eq!(
+        "https://my-bucket.s3.eu-west-1.amazonaws.com/data.bin",
+        requests[1].0
+    );
+}
+
+#[test]
+fn custom_region_not_discovered() {
+    let mock = Arc::new(Mock::default());
+    let region = Region::custom("http://localhost:9000").unwrap();
+    let client = Client::new(region, common::credentials()).with_transport(mock.clone());
+
+    let bucket = client.bucket("my-bucket").unwrap();
+    assert!(task::block_on(bucket.put_object_bytes("data.bin", b"data")).is_err());
+    assert_eq!(None, client.bucket_region("my-bucket"));
+    assert_eq!(1, mock.requests.lock().unwrap().len());
+}
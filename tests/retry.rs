@@ -0,0 +1,161 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::{Client, Clock, ErrorCode, RetryPolicy};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+use time::{macros::datetime, OffsetDateTime};
+
+/// A clock that advances by one minute whenever it is read.
+#[derive(Debug)]
+struct TickingClock(Mutex<OffsetDateTime>);
+
+impl Clock for TickingClock {
+    fn now(&self) -> OffsetDateTime {
+        let mut now = self.0.lock().unwrap();
+        *now += Duration::from_secs(60);
+        *now
+    }
+}
+
+fn policy() -> RetryPolicy {
+    RetryPolicy::new().with_base_delay(Duration::from_millis(1))
+}
+
+#[test]
+fn retry_policy() {
+    let policy = RetryPolicy::new()
+        .with_max_attempts(0)
+        .with_base_delay(Duration::from_millis(100))
+        .with_max_delay(Duration::from_millis(300));
+    assert_eq!(1, policy.max_attempts());
+    for failed in 1..10 {
+        let cap = Duration::from_millis(100 << (failed - 1)).min(Duration::from_millis(300));
+        assert!(policy.delay(failed) <= cap);
+    }
+}
+
+#[test]
+fn retry_server_errors() {
+    let count = Arc::new(AtomicUsize::new(0));
+    let counter = count.clone();
+    let server = Server::start(move |_| match counter.fetch_add(1, Ordering::SeqCst) {
+        0 => Response::error(503, "SlowDown"),
+        1 => Response::error(500, "InternalError"),
+        _ => Response::new(200).header("ETag", "\"8d777f385d3dfec8815d20f7496026dc\""),
+    });
+    let client = Client::new(server.region(), common::credentials())
+        .with_retry_policy(policy())
+        .with_clock(TickingClock(Mutex::new(datetime!(2022-06-01 0:00 UTC))));
+    let bucket = client.bucket("my-bucket").unwrap();
+
+    task::block_on(bucket.put_object_bytes("data.txt", "data")).unwrap();
+
+    // Every attempt is sent with the body and signed again.
+    let requests = server.requests();
+    assert_eq!(3, requests.len());
+    assert!(requests.iter().all(|request| request.body == b"data"));
+    let dates: Vec<_> = requests
+        .iter()
+        .map(|request| request.header("x-amz-date").unwrap())
+        .collect();
+    assert!(
+        dates.windows(2).all(|dates| dates[0] < dates[1]),
+        "{:?}",
+        dates
+    );
+}
+
+#[test]
+fn retry_attempts_exhausted() {
+    let server = Server::start(|_| Response::error(503, "SlowDown"));
+    let bucket = Client::new(server.region(), common::credentials())
+        .with_retry_policy(policy().with_max_attempts(2))
+        .bucket("my-bucket")
+        .unwrap();
+
+    let err = task::block_on(bucket.get_object_tags("data.txt")).unwrap_err();
+    assert_eq!(Some(ErrorCode::SlowDown), err.code());
+    assert!(err.is_retryable());
+    assert_eq!(2, server.requests().len());
+}
+
+#[test]
+fn retry_request_timeouts() {
+    let count = Arc::new(AtomicUsize::new(0));
+    let counter = count.clone();
+    let server = Server::start(
+        move |request| match counter.fetch_add(1, Ordering::SeqCst) {
+            0 => Response::error(400, "RequestTimeout"),
+            _ if request.method == "GET" => Response::error(400, "InvalidArgument"),
+            _ => Response::new(200).header("ETag", "\"8d777f385d3dfec8815d20f7496026dc\""),
+        },
+    );
+    let bucket = Client::new(server.region(), common::credentials())
+        .with_retry_policy(policy())
+        .bucket("my-bucket")
+        .unwrap();
+
+    task::block_on(bucket.put_object_bytes("data.txt", "data")).unwrap();
+    assert_eq!(2, server.requests().len());
+
+    // Other client errors are not retried, and their body is kept.
+    let err = task::block_on(bucket.get_object_tags("data.txt")).unwrap_err();
+    assert!(!err.is_retryable());
+    assert!(err.body().unwrap().contains("<Code>InvalidArgument</Code>"));
+    assert_eq!(3, server.requests().len());
+}
+
+#[test]
+fn retry_idempotent_only() {
+    let server = Server::start(|request| match request.method.as_str() {
+        "GET" => Response::error(404, "NoSuchKey"),
+        _ => Response::error(503, "SlowDown"),
+    });
+    let bucket = Client::new(server.region(), common::credentials())
+        .with_retry_policy(policy())
+        .bucket("my-bucket")
+        .unwrap();
+
+    // POST requests are not retried.
+    task::block_on(bucket.create_multipart_upload("data.txt")).unwrap_err();
+    assert_eq!(1, server.requests().len());
+
+    // Client errors are not retried.
+    let err = task::block_on(bucket.get_object_tags("data.txt")).unwrap_err();
+    assert_eq!(Some(ErrorCode::NoSuchKey), err.code());
+    assert_eq!(2, server.requests().len());
+}
+
+#[test]
+fn retry_disabled() {
+    let server = Server::start(|_| Response::error(503, "SlowDown"));
+    let bucket = Client::new(server.region(), common::credentials())
+        .bucket("my-bucket")
+        .unwrap();
+    assert!(bucket.client().retry_policy().is_none());
+
+    task::block_on(bucket.get_object_tags("data.txt")).unwrap_err();
+    assert_eq!(1, server.requests().len());
+}
@@ -0,0 +1,228 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::{io::Cursor, task};
+use common::{Response, Server};
+use minio::s3::{
+    compose::ComposeSource, sse::CustomerKey, Bucket, GetObjectOptions, ObjectKey,
+    PutObjectOptions, Sse,
+};
+
+const ETAG: &str = "\"d41d8cd98f00b204e9800998ecf8427e\"";
+const KEY: &str = "KioqKioqKioqKioqKioqKioqKioqKioqKioqKioqKio=";
+const KEY_MD5: &str = "SaaDheEjzuynJH8eW6AEpQ==";
+
+#[test]
+fn customer_key() {
+    let key = CustomerKey::from_slice(&[0x2a; 32]).unwrap();
+    assert_eq!(CustomerKey::new([0x2a; 32]), key);
+    assert_eq!(KEY_MD5, key.md5());
+    assert!(CustomerKey::from_slice(&[0x2a; 16]).is_none());
+
+    let debug = format!("{:?}", Sse::Customer(key));
+    assert!(debug.contains(KEY_MD5));
+    assert!(!debug.contains(KEY));
+}
+
+#[test]
+fn put_get_stat() {
+    let server = Server::start(|_| {
+        Response::new(200)
+            .header("ETag", ETAG)
+            .header("Content-Length", 0)
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+    let sse = Sse::customer([0x2a; 32]);
+
+    let options = PutObjectOptions::new().with_sse(sse.clone());
+    let data = Cursor::new(b"top secret".to_vec());
+    task::block_on(bucket.put_object_with_options("secret.txt", data, &options)).unwrap();
+
+    let options = GetObjectOptions::new().with_sse(sse);
+    task::block_on(bucket.get_object_with_options("secret.txt", &options)).unwrap();
+    task::block_on(bucket.stat_object_with_options("secret.txt", &options)).unwrap();
+    task::block_on(bucket.stat_object("secret.txt")).unwrap();
+
+    let requests = server.requests();
+    for request in &requests[..3] {
+        assert_eq!(
+            Some("AES256"),
+            request.header("x-amz-server-side-encryption-customer-algorithm")
+        );
+        assert_eq!(
+            Some(KEY),
+            request.header("x-amz-server-side-encryption-customer-key")
+        );
+        assert_eq!(
+            Some(KEY_MD5),
+            request.header("x-amz-server-side-encryption-customer-key-md5")
+        );
+    }
+    assert_eq!("HEAD", requests[2].method);
+    assert!(requests[3]
+        .header("x-amz-server-side-encryption-customer-key")
+        .is_none());
+}
+
+#[test]
+fn s3() {
+    let server = Server::start(|_| {
+        Response::new(200)
+            .header("ETag", ETAG)
+            .header("Content-Length", 0)
+            .header("x-amz-server-side-encryption", "AES256")
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let options = PutObjectOptions::new().with_sse(Sse::S3);
+    let data = Cursor::new(b"top secret".to_vec());
+    let result =
+        task::block_on(bucket.put_object_with_options("secret.txt", data, &options)).unwrap();
+    assert_eq!(Some("AES256"), result.server_side_encryption());
+
+    let metadata = task::block_on(bucket.stat_object("secret.txt")).unwrap();
+    assert_eq!(Some("AES256"), metadata.server_side_encryption());
+    assert_eq!(None, metadata.kms_key_id());
+    let object = task::block_on(bucket.get_object("secret.txt")).unwrap();
+    assert_eq!(Some("AES256"), object.metadata().server_side_encryption());
+
+    let requests = server.requests();
+    assert_eq!(
+        Some("AES256"),
+        requests[0].header("x-amz-server-side-encryption")
+    );
+    assert!(requests[1].header("x-amz-server-side-encryption").is_none());
+}
+
+#[test]
+fn kms() {
+    let server = Server::start(|request| {
+        match request.method.as_str() {
+        "POST" => Response::new(200)
+            .body("<InitiateMultipartUploadResult><UploadId>upload</UploadId></InitiateMultipartUploadResult>"),
+        _ => Response::new(200)
+            .header("ETag", ETAG)
+            .header("Content-Length", 0)
+            .header("x-amz-server-side-encryption", "aws:kms")
+            .header("x-amz-server-side-encryption-aws-kms-key-id", "my-key"),
+    }
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+    let sse = Sse::Kms {
+        key_id: Some(String::from("my-key")),
+        context: [(String::from("project"), String::from("apollo"))].into(),
+    };
+
+    let options = PutObjectOptions::new().with_sse(sse.clone());
+    let data = Cursor::new(b"top secret".to_vec());
+    let result =
+        task::block_on(bucket.put_object_with_options("secret.txt", data, &options)).unwrap();
+    assert_eq!(Some("aws:kms"), result.server_side_encryption());
+    assert_eq!(Some("my-key"), result.kms_key_id());
+
+    let options = GetObjectOptions::new().with_sse(sse.clone());
+    let metadata = task::block_on(bucket.stat_object_with_options("secret.txt", &options)).unwrap();
+    assert_eq!(Some("my-key"), metadata.kms_key_id());
+
+    let options = PutObjectOptions::new().with_sse(Sse::kms("other-key"));
+    let upload =
+        task::block_on(bucket.create_multipart_upload_with_options("large.bin", &options)).unwrap();
+    task::block_on(bucket.upload_part(&upload, 1, b"part")).unwrap();
+
+    let requests = server.requests();
+    assert_eq!(
+        Some("aws:kms"),
+        requests[0].header("x-amz-server-side-encryption")
+    );
+    assert_eq!(
+        Some("my-key"),
+        requests[0].header("x-amz-server-side-encryption-aws-kms-key-id")
+    );
+    assert_eq!(
+        Some("eyJwcm9qZWN0IjoiYXBvbGxvIn0="),
+        requests[0].header("x-amz-server-side-encryption-context")
+    );
+    assert!(requests[1].header("x-amz-server-side-encryption").is_none());
+    assert_eq!(
+        Some("other-key"),
+        requests[2].header("x-amz-server-side-encryption-aws-kms-key-id")
+    );
+    assert!(requests[2]
+        .header("x-amz-server-side-encryption-context")
+        .is_none());
+    assert!(requests[3].header("x-amz-server-side-encryption").is_none());
+}
+
+#[test]
+fn compose_object() {
+    let server = Server::start(|request| {
+        match request.method.as_str() {
+        "HEAD" => Response::new(200)
+            .header("ETag", ETAG)
+            .header("Content-Length", 1000),
+        "POST" if request.query() == Some("uploads=") => Response::new(200)
+            .body("<InitiateMultipartUploadResult><UploadId>upload</UploadId></InitiateMultipartUploadResult>"),
+        "PUT" => Response::new(200).body(format!(
+            "<CopyPartResult><ETag>{}</ETag></CopyPartResult>",
+            ETAG.replace('"', "&quot;")
+        )),
+        _ => Response::new(200).body(format!(
+            "<CompleteMultipartUploadResult><ETag>{}</ETag></CompleteMultipartUploadResult>",
+            ETAG.replace('"', "&quot;")
+        )),
+    }
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let sources = [
+        ComposeSource::new(ObjectKey::new("source").unwrap()).with_sse(Sse::customer([0x2a; 32]))
+    ];
+    let options = PutObjectOptions::new().with_sse(Sse::customer([0x07; 32]));
+    task::block_on(bucket.compose_object_with_options(&sources, "target", &options)).unwrap();
+
+    let requests = server.requests();
+    assert_eq!(4, requests.len());
+    assert_eq!(
+        Some(KEY),
+        requests[0].header("x-amz-server-side-encryption-customer-key")
+    );
+    assert!(requests[0]
+        .header("x-amz-copy-source-server-side-encryption-customer-key")
+        .is_none());
+
+    let copy = &requests[2];
+    assert_eq!("PUT", copy.method);
+    assert_eq!(
+        Some(KEY),
+        copy.header("x-amz-copy-source-server-side-encryption-customer-key")
+    );
+    assert_eq!(
+        Some(KEY_MD5),
+        copy.header("x-amz-copy-source-server-side-encryption-customer-key-md5")
+    );
+    let target = requests[1]
+        .header("x-amz-server-side-encryption-customer-key")
+        .unwrap();
+    assert_ne!(KEY, target);
+    assert_eq!(
+        Some(target),
+        copy.header("x-amz-server-side-encryption-customer-key")
+    );
+    assert!(requests[3]
+        .header("x-amz-server-side-encryption-customer-key")
+        .is_none());
+}
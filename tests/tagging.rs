@@ -0,0 +1,104 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::{io::Cursor, task};
+use common::{Response, Server};
+use minio::s3::{Bucket, PutObjectOptions, TagSet};
+
+#[test]
+fn object_tags() {
+    let server = Server::start(|request| {
+        match request.method.as_str() {
+        "GET" => Response::new(200).body(
+            "<Tagging><TagSet><Tag><Key>project</Key><Value>apollo</Value></Tag></TagSet></Tagging>",
+        ),
+        "DELETE" => Response::new(204),
+        _ => Response::new(200),
+    }
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let tags: TagSet = [("project", "apollo"), ("team", "a&b")]
+        .into_iter()
+        .collect();
+    task::block_on(bucket.put_object_tags("data.bin", &tags)).unwrap();
+    let request = &server.requests()[0];
+    assert_eq!(Some("tagging="), request.query());
+    assert!(request.header("content-md5").is_some());
+    assert_eq!(
+        "<Tagging><TagSet><Tag><Key>project</Key><Value>apollo</Value></Tag>\
+         <Tag><Key>team</Key><Value>a&amp;b</Value></Tag></TagSet></Tagging>",
+        String::from_utf8(request.body.clone()).unwrap()
+    );
+
+    let tags = task::block_on(bucket.get_object_tags("data.bin")).unwrap();
+    assert_eq!(Some("apollo"), tags.get("project"));
+
+    task::block_on(bucket.delete_object_tags("data.bin")).unwrap();
+    let request = &server.requests()[2];
+    assert_eq!("DELETE", request.method);
+    assert_eq!(Some("tagging="), request.query());
+}
+
+#[test]
+fn put_object_with_tags() {
+    let server = Server::start(|_| {
+        Response::new(200).header("ETag", "\"b10a8db164e0754105b7a99be72e3fe5\"")
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let tags: TagSet = [("project", "apollo"), ("owner", "j doe")]
+        .into_iter()
+        .collect();
+    let options = PutObjectOptions::new().with_tags(tags);
+    task::block_on(bucket.put_object_with_options("data.bin", Cursor::new("data"), &options))
+        .unwrap();
+    assert_eq!(
+        Some("owner=j%20doe&project=apollo"),
+        server.requests()[0].header("x-amz-tagging")
+    );
+}
+
+#[test]
+fn bucket_tags() {
+    let server = Server::start(|request| {
+        match (request.method.as_str(), request.path()) {
+        ("GET", "/my-bucket/") => Response::new(200).body(
+            "<Tagging><TagSet><Tag><Key>cost-center</Key><Value>research</Value></Tag></TagSet></Tagging>",
+        ),
+        ("GET", _) => Response::error(404, "NoSuchTagSet"),
+        ("DELETE", _) => Response::new(204),
+        _ => Response::new(204),
+    }
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let tags: TagSet = [("cost-center", "research")].into_iter().collect();
+    task::block_on(bucket.set_tags(&tags)).unwrap();
+    let request = &server.requests()[0];
+    assert_eq!("PUT", request.method);
+    assert_eq!(Some("tagging="), request.query());
+    assert!(request.header("content-md5").is_some());
+
+    assert_eq!(tags, task::block_on(bucket.get_tags()).unwrap());
+
+    task::block_on(bucket.delete_tags()).unwrap();
+    assert_eq!("DELETE", server.requests()[2].method);
+
+    let bucket = Bucket::new("untagged", server.region(), common::credentials()).unwrap();
+    assert!(task::block_on(bucket.get_tags()).unwrap().is_empty());
+}
@@ -0,0 +1,72 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_std::{
+    io::{Cursor, ReadExt, WriteExt},
+    task,
+};
+use futures::future;
+use minio::s3::{
+    throttle::{RateLimiter, Throttled},
+    Payload,
+};
+use std::time::{Duration, Instant};
+
+#[test]
+fn throttle_reader() {
+    // The first 20 KB are a burst, the remaining 10 KB
+    // take about half a second.
+    let data = vec![0u8; 30_000];
+    let mut reader = Throttled::new(Cursor::new(data.clone())).with_limit(RateLimiter::new(20_000));
+    assert_eq!(Some(30_000), Payload::len(&reader));
+
+    let start = Instant::now();
+    let mut read = Vec::new();
+    task::block_on(reader.read_to_end(&mut read)).unwrap();
+    assert_eq!(data, read);
+    assert!(start.elapsed() >= Duration::from_millis(400));
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+#[test]
+fn throttle_shared_writers() {
+    // Two writers share a global limit of 20 KB/s, while each
+    // writer has its own, less restrictive, limit.
+    let global = RateLimiter::new(20_000);
+    let write = |global: RateLimiter| async move {
+        let mut writer = Throttled::new(Vec::new())
+            .with_limit(global)
+            .with_limit(RateLimiter::new(1_000_000));
+        writer.write_all(&[1u8; 15_000]).await.unwrap();
+        writer.into_inner()
+    };
+
+    let start = Instant::now();
+    let written = task::block_on(future::join(write(global.clone()), write(global)));
+    assert_eq!((15_000, 15_000), (written.0.len(), written.1.len()));
+    assert!(start.elapsed() >= Duration::from_millis(400));
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+#[test]
+fn unthrottled() {
+    let mut reader = Throttled::new(Cursor::new(vec![0u8; 1 << 20]));
+    let start = Instant::now();
+    let mut read = Vec::new();
+    task::block_on(reader.read_to_end(&mut read)).unwrap();
+    assert_eq!(1 << 20, read.len());
+    assert!(start.elapsed() < Duration::from_secs(1));
+    assert_eq!(5, RateLimiter::new(5).bytes_per_sec());
+}
@@ -0,0 +1,123 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::{ownership::ObjectOwnership, tiering::*, Bucket};
+use std::sync::{Arc, Mutex};
+
+fn storing_server() -> Server {
+    let stored = Arc::new(Mutex::new(Vec::new()));
+    Server::start(move |request| match request.method.as_str() {
+        "PUT" => {
+            *stored.lock().unwrap() = request.body.clone();
+            Response::new(200)
+        }
+        "DELETE" => Response::new(204),
+        _ => Response::new(200).body(stored.lock().unwrap().clone()),
+    })
+}
+
+#[test]
+fn ownership_controls() {
+    let server = storing_server();
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    task::block_on(bucket.put_ownership_controls(ObjectOwnership::BucketOwnerPreferred)).unwrap();
+    let requests = server.requests();
+    assert_eq!(Some("ownershipControls="), requests[0].query());
+    let body = String::from_utf8(requests[0].body.clone()).unwrap();
+    assert!(body.contains("<Rule><ObjectOwnership>BucketOwnerPreferred</ObjectOwnership></Rule>"));
+
+    assert_eq!(
+        ObjectOwnership::BucketOwnerPreferred,
+        task::block_on(bucket.get_ownership_controls()).unwrap()
+    );
+
+    task::block_on(bucket.delete_ownership_controls()).unwrap();
+    assert_eq!("DELETE", server.requests()[2].method);
+}
+
+#[test]
+fn intelligent_tiering_configuration() {
+    let server = storing_server();
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let config = IntelligentTieringConfiguration::new("archive")
+        .with_enabled(false)
+        .with_filter(TieringFilter::new().with_tag("class", "cold"))
+        .with_tiering(AccessTier::ArchiveAccess, 90)
+        .with_tiering(AccessTier::DeepArchiveAccess, 180);
+
+    task::block_on(bucket.put_intelligent_tiering_configuration(&config)).unwrap();
+    let requests = server.requests();
+    assert_eq!(Some("intelligent-tiering=&id=archive"), requests[0].query());
+    let body = String::from_utf8(requests[0].body.clone()).unwrap();
+    assert!(body.contains("<Filter><Tag><Key>class</Key><Value>cold</Value></Tag></Filter>"));
+    assert!(body.contains("<Status>Disabled</Status>"));
+    assert!(
+        body.contains("<Tiering><AccessTier>ARCHIVE_ACCESS</AccessTier><Days>90</Days></Tiering>")
+    );
+
+    assert_eq!(
+        config,
+        task::block_on(bucket.get_intelligent_tiering_configuration("archive")).unwrap()
+    );
+
+    task::block_on(bucket.delete_intelligent_tiering_configuration("archive")).unwrap();
+    assert_eq!("DELETE", server.requests()[2].method);
+}
+
+#[test]
+fn list_intelligent_tiering_configurations() {
+    let server = Server::start(move |request| {
+        let body = match request.query() {
+            Some("intelligent-tiering=") => "<ListBucketIntelligentTieringConfigurationsOutput>\
+                 <IntelligentTieringConfiguration><Id>first</Id><Status>Enabled</Status>\
+                 <Tiering><AccessTier>DEEP_ARCHIVE_ACCESS</AccessTier><Days>365</Days></Tiering>\
+                 </IntelligentTieringConfiguration><IsTruncated>true</IsTruncated>\
+                 <NextContinuationToken>next</NextContinuationToken>\
+                 </ListBucketIntelligentTieringConfigurationsOutput>",
+            _ => "<ListBucketIntelligentTieringConfigurationsOutput>\
+                 <IntelligentTieringConfiguration><Id>second</Id><Status>Enabled</Status>\
+                 <Filter><And><Prefix>logs/</Prefix><Tag><Key>a</Key><Value>b</Value></Tag></And></Filter>\
+                 <Tiering><AccessTier>ARCHIVE_ACCESS</AccessTier><Days>90</Days></Tiering>\
+                 </IntelligentTieringConfiguration><IsTruncated>false</IsTruncated>\
+                 </ListBucketIntelligentTieringConfigurationsOutput>",
+        };
+        Response::new(200).body(body)
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let configs = task::block_on(bucket.list_intelligent_tiering_configurations()).unwrap();
+    let ids: Vec<&str> = configs
+        .iter()
+        .map(IntelligentTieringConfiguration::id)
+        .collect();
+    assert_eq!(vec!["first", "second"], ids);
+    assert_eq!(
+        AccessTier::DeepArchiveAccess,
+        configs[0].tierings()[0].access_tier()
+    );
+    assert_eq!(365, configs[0].tierings()[0].days());
+    assert_eq!(Some("logs/"), configs[1].filter().prefix());
+    assert_eq!(Some("b"), configs[1].filter().tags().get("a"));
+    assert_eq!(
+        Some("intelligent-tiering=&continuation-token=next"),
+        server.requests()[1].query()
+    );
+}
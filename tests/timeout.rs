@@ -0,0 +1,80 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::{Client, Region, RetryPolicy, Timeouts};
+use std::time::{Duration, Instant};
+
+#[test]
+fn request_timeout() {
+    // The listener accepts connections, but never responds.
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let endpoint = format!("http://{}", listener.local_addr().unwrap());
+    let region = Region::custom_with_region(&endpoint, "us-east-1").unwrap();
+    let timeouts = Timeouts::new().with_request(Duration::from_millis(200));
+    let bucket = Client::new(region, common::credentials())
+        .with_timeouts(timeouts)
+        .bucket("my-bucket")
+        .unwrap();
+
+    let start = Instant::now();
+    let err = task::block_on(bucket.stat_object("slow.txt")).unwrap_err();
+    assert!(err.is_timeout(), "{}", err);
+    assert!(start.elapsed() < Duration::from_secs(5));
+
+    // Fast requests are not affected.
+    let server = Server::start(|_| {
+        Response::new(200)
+            .header("ETag", "\"8d777f385d3dfec8815d20f7496026dc\"")
+            .header("Content-Length", 4)
+    });
+    let bucket = Client::new(server.region(), common::credentials())
+        .with_timeouts(timeouts)
+        .bucket("my-bucket")
+        .unwrap();
+    task::block_on(bucket.stat_object("fast.txt")).unwrap();
+}
+
+#[test]
+fn operation_timeout() {
+    let server = Server::start(|_| Response::error(503, "SlowDown"));
+    let timeouts = Timeouts::new().with_operation(Duration::from_millis(300));
+    let retry = RetryPolicy::new()
+        .with_max_attempts(100)
+        .with_base_delay(Duration::from_millis(50))
+        .with_max_delay(Duration::from_millis(50));
+    let bucket = Client::new(server.region(), common::credentials())
+        .with_timeouts(timeouts)
+        .with_retry_policy(retry)
+        .bucket("my-bucket")
+        .unwrap();
+
+    let start = Instant::now();
+    let err = task::block_on(bucket.get_object_tags("data.txt")).unwrap_err();
+    assert!(err.is_timeout(), "{}", err);
+    assert!(start.elapsed() < Duration::from_secs(2));
+    assert!(server.requests().len() < 100);
+}
+
+#[test]
+fn connect_timeout() {
+    let timeouts = Timeouts::new().with_connect(Duration::from_millis(500));
+    let client = Client::new(Region::UsEast1, common::credentials()).with_timeouts(timeouts);
+    assert_eq!(timeouts, client.timeouts());
+    assert_eq!(None, Timeouts::new().request());
+}
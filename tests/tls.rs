@@ -0,0 +1,134 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::{
+    tls::{Certificate, Identity, Tls},
+    Bucket, Client,
+};
+use std::path::Path;
+
+const PEM: &str = "-----BEGIN CERTIFICATE-----
+MIIBszCCAVmgAwIBAgIUMinIOExampleRootCertificate0wCgYIKoZIzj0EAwIw
+-----END CERTIFICATE-----
+";
+
+#[test]
+fn parse_certificate() {
+    let certificate = Certificate::from_pem(PEM).unwrap();
+    assert_eq!(1, certificate.len());
+    assert_eq!(PEM.trim(), certificate.as_pem());
+
+    let bundle = format!("{}{}", PEM, PEM);
+    assert_eq!(2, Certificate::from_pem(bundle).unwrap().len());
+
+    assert!(Certificate::from_pem("").is_err());
+    assert!(Certificate::from_pem(&PEM[..40]).is_err());
+    assert!(Certificate::from_pem([0xff, 0xfe]).is_err());
+}
+
+#[test]
+fn tls_settings() {
+    let client = Client::new(
+        Server::start(|_| Response::new(200)).region(),
+        common::credentials(),
+    );
+    assert!(client.tls().root_certificates().is_empty());
+    assert!(client.tls().system_roots());
+    assert!(!client.tls().insecure_skip_verify());
+
+    let tls = Tls::new()
+        .with_root_certificate(Certificate::from_pem(PEM).unwrap())
+        .with_system_roots(false)
+        .with_insecure_skip_verify(true);
+    let client = client.with_tls(tls);
+    assert_eq!(1, client.tls().root_certificates().len());
+    assert!(!client.tls().system_roots());
+    assert!(client.tls().insecure_skip_verify());
+}
+
+#[test]
+fn tls_does_not_apply_to_http() {
+    let server = Server::start(|_| Response::new(200));
+    let tls = Tls::new().with_root_certificate(Certificate::from_pem(PEM).unwrap());
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials())
+        .unwrap()
+        .with_tls(tls);
+
+    assert!(task::block_on(bucket.exists()).unwrap());
+    assert_eq!(1, server.requests().len());
+}
+
+#[test]
+fn root_certificates_for_https() {
+    let server = Server::start(|_| Response::new(200));
+    let endpoint = server.endpoint().replace("http://", "https://");
+    let region = minio::s3::Region::custom(endpoint).unwrap();
+
+    let pem = PEM.replace("Example", "Bundled");
+    let tls = Tls::new()
+        .with_root_certificate(Certificate::from_pem(&pem).unwrap())
+        .with_system_roots(false);
+    let bucket = Bucket::new("my-bucket", region, common::credentials())
+        .unwrap()
+        .with_tls(tls);
+
+    // The server does not speak TLS, so the handshake fails.
+    assert!(task::block_on(bucket.exists()).is_err());
+
+    let bundled = || {
+        std::fs::read_dir(std::env::temp_dir())
+            .unwrap()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| {
+                let name = entry.file_name();
+                let name = name.to_string_lossy();
+                name.starts_with("minio-ca-") && name.ends_with(".pem")
+            })
+            .filter(|entry| {
+                std::fs::read_to_string(entry.path()).ok() == Some(format!("{}\n", pem.trim()))
+            })
+            .max_by_key(|entry| entry.metadata().unwrap().modified().unwrap())
+    };
+    let bundle = bundled().expect("bundle in temporary directory");
+    // Only the current user may read or replace the bundle.
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = bundle.metadata().unwrap().permissions().mode();
+        assert_eq!(0o600, mode & 0o777);
+    }
+
+    drop(bucket);
+    assert!(!bundle.path().exists());
+}
+
+#[test]
+fn client_identity() {
+    let identity = Identity::from_pem_files("client.crt", "client.key").with_password("secret");
+    assert_eq!(Path::new("client.crt"), identity.certificate_path());
+    assert_eq!(Some(Path::new("client.key")), identity.key_path());
+    assert!(!format!("{:?}", identity).contains("secret"));
+
+    let identity = Identity::from_pkcs12_file("client.p12");
+    assert_eq!(Path::new("client.p12"), identity.certificate_path());
+    assert_eq!(None, identity.key_path());
+
+    let tls = Tls::new().with_identity(identity.clone());
+    assert_eq!(Some(&identity), tls.identity());
+}
@@ -0,0 +1,249 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "tracing")]
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::{request::Method, Client, RetryPolicy};
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+use tracing::{
+    field::{Field, Visit},
+    span, Event, Level, Metadata, Subscriber,
+};
+
+/// The fields of a span or an event.
+#[derive(Debug, Default, Clone)]
+struct Fields(HashMap<String, String>);
+
+impl Fields {
+    fn get(&self, name: &str) -> Option<&str> {
+        self.0.get(name).map(String::as_str)
+    }
+}
+
+impl Visit for Fields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_owned(), value.to_owned());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0
+            .insert(field.name().to_owned(), format!("{:?}", value));
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RecordedEvent {
+    target: &'static str,
+    level: Level,
+    span: Option<&'static str>,
+    fields: Fields,
+}
+
+/// A subscriber that records all spans and events.
+#[derive(Debug, Default, Clone)]
+struct Recorder {
+    spans: Arc<Mutex<Vec<(&'static Metadata<'static>, Fields)>>>,
+    events: Arc<Mutex<Vec<RecordedEvent>>>,
+    entered: Arc<Mutex<Vec<usize>>>,
+}
+
+impl Recorder {
+    /// Returns the fields of the spans of this crate.
+    fn spans(&self) -> Vec<Fields> {
+        let spans = self.spans.lock().unwrap();
+        spans
+            .iter()
+            .filter(|(metadata, _)| metadata.target().starts_with("minio"))
+            .map(|(_, fields)| fields.clone())
+            .collect()
+    }
+
+    /// Returns the events of this crate.
+    fn events(&self) -> Vec<RecordedEvent> {
+        let events = self.events.lock().unwrap();
+        events
+            .iter()
+            .filter(|event| event.target.starts_with("minio"))
+            .cloned()
+            .collect()
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+        let mut fields = Fields::default();
+        span.record(&mut fields);
+        let mut spans = self.spans.lock().unwrap();
+        spans.push((span.metadata(), fields));
+        span::Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, span: &span::Id, values: &span::Record<'_>) {
+        let index = span.into_u64() as usize - 1;
+        values.record(&mut self.spans.lock().unwrap()[index].1);
+    }
+
+    fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = Fields::default();
+        event.record(&mut fields);
+        self.events.lock().unwrap().push(RecordedEvent {
+            target: event.metadata().target(),
+            level: *event.metadata().level(),
+            span: self
+                .entered
+                .lock()
+                .unwrap()
+                .last()
+                .map(|&index| self.spans.lock().unwrap()[index].0.name()),
+            fields,
+        });
+    }
+
+    fn enter(&self, span: &span::Id) {
+        self.entered
+            .lock()
+            .unwrap()
+            .push(span.into_u64() as usize - 1);
+    }
+
+    fn exit(&self, _: &span::Id) {
+        self.entered.lock().unwrap().pop();
+    }
+
+    fn current_span(&self) -> tracing_core::span::Current {
+        match self.entered.lock().unwrap().last() {
+            Some(&index) => tracing_core::span::Current::new(
+                span::Id::from_u64(index as u64 + 1),
+                self.spans.lock().unwrap()[index].0,
+            ),
+            None => tracing_core::span::Current::none(),
+        }
+    }
+}
+
+fn ok(_: &common::Request) -> Response {
+    Response::new(200).header("ETag", "\"8d777f385d3dfec8815d20f7496026dc\"")
+}
+
+#[test]
+fn operation_spans() {
+    let server = Server::start(ok);
+    let client = Client::new(server.region(), common::credentials());
+    let bucket = client.bucket("my-bucket").unwrap();
+    let recorder = Recorder::default();
+
+    tracing::subscriber::with_default(recorder.clone(), || {
+        task::block_on(bucket.put_object_bytes("photos/a b.jpg", "a")).unwrap();
+        let request = client
+            .request(Method::Get, "my-bucket")
+            .unwrap()
+            .query("versioning", "")
+            .sign_empty(client.credentials())
+            .unwrap();
+        task::block_on(client.execute(request)).unwrap();
+    });
+
+    let spans = recorder.spans();
+    assert_eq!(2, spans.len());
+    assert_eq!(Some("PutObject"), spans[0].get("operation"));
+    assert_eq!(Some("my-bucket"), spans[0].get("bucket"));
+    assert_eq!(Some("photos/a b.jpg"), spans[0].get("key"));
+    assert_eq!(Some("200"), spans[0].get("status"));
+    assert!(spans[0].get("latency_ms").is_some());
+    assert_eq!(None, spans[0].get("retries"));
+
+    assert_eq!(Some("GetBucketVersioning"), spans[1].get("operation"));
+    assert_eq!(Some("my-bucket"), spans[1].get("bucket"));
+    assert_eq!(None, spans[1].get("key"));
+}
+
+#[test]
+fn retry_events() {
+    let count = Arc::new(AtomicUsize::new(0));
+    let counter = count.clone();
+    let server = Server::start(
+        move |request| match counter.fetch_add(1, Ordering::SeqCst) {
+            0 => Response::error(503, "SlowDown"),
+            _ => ok(request),
+        },
+    );
+    let bucket = Client::new(server.region(), common::credentials())
+        .with_retry_policy(RetryPolicy::new().with_base_delay(Duration::from_millis(1)))
+        .bucket("my-bucket")
+        .unwrap();
+    let recorder = Recorder::default();
+
+    tracing::subscriber::with_default(recorder.clone(), || {
+        task::block_on(bucket.put_object_bytes("a.txt", "a")).unwrap();
+    });
+
+    let spans = recorder.spans();
+    assert_eq!(Some("1"), spans[0].get("retries"));
+    assert_eq!(Some("200"), spans[0].get("status"));
+    let events = recorder.events();
+    let retry = events
+        .iter()
+        .find(|event| event.fields.get("message") == Some("retrying request"))
+        .unwrap();
+    assert_eq!(Level::INFO, retry.level);
+    assert_eq!(Some("s3"), retry.span);
+    assert_eq!(Some("503"), retry.fields.get("status"));
+}
+
+#[test]
+fn signature_errors() {
+    let server = Server::start(|_| Response::error(403, "SignatureDoesNotMatch"));
+    let bucket = Client::new(server.region(), common::credentials())
+        .bucket("my-bucket")
+        .unwrap();
+    let recorder = Recorder::default();
+
+    let err = tracing::subscriber::with_default(recorder.clone(), || {
+        task::block_on(bucket.delete_object("a.txt")).unwrap_err()
+    });
+
+    assert!(err.to_string().contains("SignatureDoesNotMatch"));
+    let spans = recorder.spans();
+    assert_eq!(Some("DeleteObject"), spans[0].get("operation"));
+    assert_eq!(Some("403"), spans[0].get("status"));
+    let events = recorder.events();
+    let warning = events
+        .iter()
+        .find(|event| event.level == Level::WARN)
+        .unwrap();
+    assert_eq!(
+        Some("signature does not match"),
+        warning.fields.get("message")
+    );
+    assert_eq!(Some("s3"), warning.span);
+}
@@ -0,0 +1,204 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::{
+    throttle::RateLimiter,
+    transfer::{Direction, Transfer, TransferManager},
+    Bucket, ErrorKind, ObjectKey,
+};
+
+const DATA: &[u8] = b"0123456789";
+const ETAG: &str = "\"781e5e245d69b566979b86e28d23f2c7\"";
+
+fn server() -> Server {
+    Server::start(|request| {
+        if request.path() == "/my-bucket/missing.bin" {
+            return Response::new(404);
+        }
+        match request.method.as_str() {
+            "POST" if request.query() == Some("uploads=") => Response::new(200).body(
+                "<InitiateMultipartUploadResult><UploadId>upload-1</UploadId></InitiateMultipartUploadResult>",
+            ),
+            "POST" => Response::new(200).body(format!(
+                "<CompleteMultipartUploadResult><ETag>{}</ETag></CompleteMultipartUploadResult>",
+                ETAG.replace('"', "&quot;")
+            )),
+            "PUT" => Response::new(200).header("ETag", ETAG),
+            "HEAD" => Response::new(200)
+                .header("ETag", ETAG)
+                .header("Content-Length", DATA.len()),
+            _ => Response::new(206)
+                .header("ETag", ETAG)
+                .header("Content-Range", format!("bytes 0-9/{}", DATA.len()))
+                .body(DATA),
+        }
+    })
+}
+
+#[test]
+fn transfer_manager() {
+    let server = server();
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+    let dir = std::env::temp_dir().join(format!("minio-transfer-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("upload.bin"), DATA).unwrap();
+
+    let key = |key: &str| ObjectKey::new(key).unwrap();
+    let transfers = vec![
+        Transfer::upload(dir.join("upload.bin"), key("upload.bin")),
+        Transfer::upload(dir.join("nonexistent.bin"), key("nonexistent.bin")),
+        Transfer::download(key("download.bin"), dir.join("download.bin")).with_rate_limit(1 << 20),
+        Transfer::download(key("missing.bin"), dir.join("missing.bin")).with_attempts(5),
+    ];
+    let manager = TransferManager::new(&bucket)
+        .with_concurrency(2)
+        .with_upload_limit(RateLimiter::new(1 << 20))
+        .with_download_limit(RateLimiter::new(1 << 20));
+    let mut updates = Vec::new();
+    let report = task::block_on(manager.run_with_progress(transfers, |progress| {
+        assert_eq!(4, progress.total());
+        updates.push(*progress);
+    }));
+
+    assert_eq!(4, updates.len());
+    assert_eq!(
+        (1..=4).collect::<Vec<_>>(),
+        updates.iter().map(|p| p.finished()).collect::<Vec<_>>()
+    );
+    let last = updates.last().unwrap();
+    assert_eq!((2, 2, 20), (last.completed(), last.failed(), last.bytes()));
+
+    assert!(!report.is_success());
+    assert_eq!(20, report.bytes());
+    let mut completed: Vec<_> = report
+        .completed()
+        .iter()
+        .map(|(transfer, _)| (transfer.direction(), transfer.key().as_str()))
+        .collect();
+    completed.sort_by_key(|(_, key)| *key);
+    assert_eq!(
+        vec![
+            (Direction::Download, "download.bin"),
+            (Direction::Upload, "upload.bin")
+        ],
+        completed
+    );
+    assert_eq!(
+        DATA,
+        std::fs::read(dir.join("download.bin")).unwrap().as_slice()
+    );
+
+    let mut failed: Vec<_> = report
+        .failed()
+        .iter()
+        .map(|(transfer, _)| transfer.key().as_str())
+        .collect();
+    failed.sort();
+    assert_eq!(vec!["missing.bin", "nonexistent.bin"], failed);
+
+    // The missing object is not retried since it is not a
+    // transient error.
+    let missing = server
+        .requests()
+        .iter()
+        .filter(|request| request.path() == "/my-bucket/missing.bin")
+        .count();
+    assert_eq!(1, missing);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn large_files_are_uploaded_in_parts() {
+    let server = server();
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials())
+        .unwrap()
+        .with_part_size(5 << 20);
+    let dir = std::env::temp_dir().join(format!("minio-transfer-parts-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("large.bin"), vec![0; (5 << 20) + 1]).unwrap();
+
+    let transfers = vec![Transfer::upload(
+        dir.join("large.bin"),
+        ObjectKey::new("large.bin").unwrap(),
+    )];
+    let report = task::block_on(TransferManager::new(&bucket).run(transfers));
+    assert!(report.is_success());
+    assert_eq!((5 << 20) + 1, report.bytes());
+
+    let requests: Vec<_> = server
+        .requests()
+        .iter()
+        .map(|request| (request.method.clone(), request.query().map(String::from)))
+        .collect();
+    assert_eq!(4, requests.len());
+    assert_eq!(
+        ("POST".to_string(), Some("uploads=".to_string())),
+        requests[0]
+    );
+    for (method, query) in &requests[1..3] {
+        assert_eq!("PUT", method);
+        assert!(query.as_deref().unwrap().starts_with("partNumber="));
+    }
+    assert_eq!(
+        ("POST".to_string(), Some("uploadId=upload-1".to_string())),
+        requests[3]
+    );
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn local_errors_are_not_retried() {
+    let server = server();
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("missing.bin"), b"old").unwrap();
+
+    // A directory cannot be uploaded as file.
+    let transfers = vec![
+        Transfer::upload(dir.path(), ObjectKey::new("dir").unwrap()).with_attempts(3),
+        Transfer::download(
+            ObjectKey::new("missing.bin").unwrap(),
+            dir.path().join("missing.bin"),
+        ),
+    ];
+    let report = task::block_on(TransferManager::new(&bucket).run(transfers));
+    assert_eq!(2, report.failed().len());
+    let (_, err) = report
+        .failed()
+        .iter()
+        .find(|(transfer, _)| transfer.direction() == Direction::Upload)
+        .unwrap();
+    assert!(matches!(err.kind(), ErrorKind::Io(_)));
+    assert!(!err.is_retryable());
+    assert!(server
+        .requests()
+        .iter()
+        .all(|request| request.path() != "/my-bucket/dir"));
+
+    // A failed download leaves an existing file untouched.
+    assert_eq!(
+        b"old",
+        std::fs::read(dir.path().join("missing.bin"))
+            .unwrap()
+            .as_slice()
+    );
+    assert_eq!(1, std::fs::read_dir(dir.path()).unwrap().count());
+}
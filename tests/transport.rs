@@ -0,0 +1,92 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use minio::s3::{
+    transport::{async_trait, Transport},
+    Client, Region, RetryPolicy,
+};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use surf::http::{Method, Request, Response, Result, StatusCode};
+
+/// A transport that records requests and answers them
+/// without any network access.
+#[derive(Debug, Default)]
+struct Mock {
+    requests: Mutex<Vec<(Method, String, bool)>>,
+}
+
+#[async_trait]
+impl Transport for Mock {
+    async fn send(&self, request: Request) -> Result<Response> {
+        let signed = request.header("Authorization").is_some();
+        self.requests.lock().unwrap().push((
+            request.method(),
+            request.url().path().to_owned(),
+            signed,
+        ));
+
+        if request.url().path() == "/my-bucket/unreachable.txt" {
+            return Err(surf::Error::from_str(
+                StatusCode::ServiceUnavailable,
+                "connection refused",
+            ));
+        }
+        let mut response = Response::new(StatusCode::Ok);
+        response.set_body(
+            "<Tagging><TagSet><Tag><Key>project</Key><Value>apollo</Value></Tag></TagSet></Tagging>",
+        );
+        Ok(response)
+    }
+}
+
+#[test]
+fn custom_transport() {
+    let mock = Arc::new(Mock::default());
+    let bucket = Client::new(Region::UsEast1, common::credentials())
+        .with_transport(mock.clone())
+        .bucket("my-bucket")
+        .unwrap();
+
+    let tags = task::block_on(bucket.get_object_tags("data.txt")).unwrap();
+    assert_eq!(Some("apollo"), tags.get("project"));
+
+    let requests = mock.requests.lock().unwrap().clone();
+    assert_eq!(
+        vec![(Method::Get, String::from("/my-bucket/data.txt"), true)],
+        requests
+    );
+}
+
+#[test]
+fn custom_transport_errors() {
+    let mock = Arc::new(Mock::default());
+    let retry = RetryPolicy::new().with_base_delay(Duration::from_millis(1));
+    let bucket = Client::new(Region::UsEast1, common::credentials())
+        .with_transport(mock.clone())
+        .with_retry_policy(retry)
+        .bucket("my-bucket")
+        .unwrap();
+
+    // Errors of the transport are retried like transport errors
+    // of the default transport.
+    task::block_on(bucket.get_object_tags("unreachable.txt")).unwrap_err();
+    assert_eq!(3, mock.requests.lock().unwrap().len());
+}
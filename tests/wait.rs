@@ -0,0 +1,86 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use minio::s3::{Bucket, WaitPolicy};
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+fn policy(timeout: Duration) -> WaitPolicy {
+    WaitPolicy::new(timeout).with_delay(Duration::from_millis(5), Duration::from_millis(20))
+}
+
+#[test]
+fn wait_until_object_exists() {
+    // The object appears after the third request.
+    let count = AtomicUsize::new(0);
+    let server = Server::start(move |_| match count.fetch_add(1, Ordering::SeqCst) {
+        0..=2 => Response::new(404),
+        _ => Response::new(200),
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    task::block_on(bucket.wait_until_object_exists("object", &policy(Duration::from_secs(10))))
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(4, requests.len());
+    assert_eq!("HEAD", requests[0].method);
+    assert_eq!("/my-bucket/object", requests[0].path());
+}
+
+#[test]
+fn wait_until_deleted() {
+    let server = Server::start(|_| Response::new(200));
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let err =
+        task::block_on(bucket.wait_until_deleted(&policy(Duration::from_millis(50)))).unwrap_err();
+    assert!(err.is_timeout(), "unexpected error: {}", err);
+    assert!(server.requests().len() > 1);
+
+    let server = Server::start(|_| Response::new(404));
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+    task::block_on(bucket.wait_until_deleted(&policy(Duration::from_millis(50)))).unwrap();
+    assert_eq!("/my-bucket/", server.requests()[0].path());
+}
+
+#[test]
+fn delete_and_wait_until_deleted() {
+    let server = Server::start(|request| match request.method.as_str() {
+        "DELETE" => Response::new(204),
+        _ => Response::new(404),
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    task::block_on(async {
+        bucket.clone().delete().await.unwrap();
+        bucket
+            .wait_until_deleted(&policy(Duration::from_millis(50)))
+            .await
+            .unwrap();
+    });
+
+    let requests = server.requests();
+    assert_eq!("DELETE", requests[0].method);
+    assert_eq!("/my-bucket/", requests[0].path());
+    assert_eq!("HEAD", requests[1].method);
+    assert_eq!("/my-bucket/", requests[1].path());
+}
@@ -0,0 +1,71 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2022 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+use async_std::task;
+use common::{Response, Server};
+use futures::{StreamExt, TryStreamExt};
+use minio::s3::{watch::Event, Bucket};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+fn listing(objects: &[(&str, &str)]) -> String {
+    let mut xml = String::from("<ListBucketResult><IsTruncated>false</IsTruncated>");
+    for (key, etag) in objects {
+        xml.push_str(&format!(
+            "<Contents><Key>{}</Key><LastModified>2022-01-01T00:00:00.000Z</LastModified><ETag>\"{}\"</ETag><Size>1</Size></Contents>",
+            key, etag
+        ));
+    }
+    xml.push_str("</ListBucketResult>");
+    xml
+}
+
+#[test]
+fn watch() {
+    const A: &str = "d41d8cd98f00b204e9800998ecf8427e";
+    const B: &str = "b10a8db164e0754105b7a99be72e3fe5";
+
+    let listings = Arc::new(Mutex::new(vec![
+        listing(&[("a", A), ("b", A)]),
+        listing(&[("a", A), ("b", A)]),
+        listing(&[("a", B), ("c", A)]),
+    ]));
+    let server = Server::start(move |_| {
+        let mut listings = listings.lock().unwrap();
+        let body = match listings.len() {
+            1 => listings[0].clone(),
+            _ => listings.remove(0),
+        };
+        Response::new(200).body(body)
+    });
+    let bucket = Bucket::new("my-bucket", server.region(), common::credentials()).unwrap();
+
+    let events: Vec<Event> = task::block_on(
+        bucket
+            .watch("", Duration::from_millis(10))
+            .take(3)
+            .try_collect(),
+    )
+    .unwrap();
+
+    assert_eq!(3, events.len());
+    assert!(matches!(events[0], Event::Modified(ref object) if object.key() == "a"));
+    assert_eq!(Event::Deleted(String::from("b")), events[1]);
+    assert!(matches!(events[2], Event::Created(ref object) if object.key() == "c"));
+}